@@ -0,0 +1,177 @@
+use std::{convert::Infallible, fmt, str::FromStr};
+
+/// The answer to one part of a puzzle.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Int(i128),
+    Text(String),
+    /// A picture drawn by the puzzle, one string per row.
+    Grid(Vec<String>),
+    /// The part has not been solved yet.
+    Unsolved,
+}
+
+impl Answer {
+    /// The answer as it would be typed into the answer box, if it can be.
+    pub fn submission(&self) -> Option<String> {
+        match self {
+            Answer::Int(n) => Some(n.to_string()),
+            Answer::Text(s) => Some(s.clone()),
+            Answer::Grid(_) | Answer::Unsolved => None,
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        match self {
+            Answer::Int(n) => n.to_string(),
+            Answer::Text(s) => json_string(s),
+            Answer::Grid(rows) => format!(
+                "[{}]",
+                rows.iter()
+                    .map(|row| json_string(row))
+                    .collect::<Vec<String>>()
+                    .join(",")
+            ),
+            Answer::Unsolved => "null".to_string(),
+        }
+    }
+
+    pub fn is_solved(&self) -> bool {
+        *self != Answer::Unsolved
+    }
+}
+
+/// Quotes and escapes `s` as a JSON string.
+pub fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+            Answer::Grid(rows) => write!(f, "{}", rows.join("\n")),
+            Answer::Unsolved => write!(f, "-"),
+        }
+    }
+}
+
+/// Reads an answer back from its plain format.
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim_end_matches('\n');
+        Ok(if s == "-" || s.is_empty() {
+            Answer::Unsolved
+        } else if s.contains('\n') {
+            Answer::Grid(s.lines().map(String::from).collect())
+        } else if let Ok(n) = s.parse() {
+            Answer::Int(n)
+        } else {
+            Answer::Text(s.to_string())
+        })
+    }
+}
+
+macro_rules! int_answer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::Int(n as i128)
+                }
+            }
+
+            impl PartialEq<$t> for Answer {
+                fn eq(&self, other: &$t) -> bool {
+                    *self == Answer::Int(*other as i128)
+                }
+            }
+        )*
+    };
+}
+
+int_answer!(i32, i64, i128, u8, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+impl From<Vec<String>> for Answer {
+    fn from(rows: Vec<String>) -> Self {
+        Answer::Grid(rows)
+    }
+}
+
+/// Compares with the plain format, so `Answer::Int(42) == "42"`.
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        match self {
+            Answer::Int(n) => other.parse::<i128>() == Ok(*n),
+            Answer::Text(s) => s == other,
+            Answer::Grid(rows) => rows.iter().map(String::as_str).eq(other.lines()),
+            Answer::Unsolved => *other == "-",
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn compare() {
+        assert_eq!(Answer::from(24000u32), 24000);
+        assert_eq!(Answer::from(24000u32), "24000");
+        assert_eq!(Answer::from("CMZ"), "CMZ");
+        assert_ne!(Answer::from("24000"), Answer::from(24000));
+    }
+
+    #[test]
+    fn formats() {
+        let grid = Answer::from(vec!["#.".to_string(), ".#".to_string()]);
+        assert_eq!(grid.to_string(), "#.\n.#");
+        assert_eq!(grid.to_json(), r##"["#.",".#"]"##);
+        assert_eq!(grid.submission(), None);
+        assert_eq!(Answer::from("a\"b").to_json(), r#""a\"b""#);
+        assert_eq!(Answer::from(-3).to_json(), "-3");
+        assert_eq!(Answer::Unsolved.to_json(), "null");
+        assert_eq!(Answer::from(7usize).submission(), Some("7".to_string()));
+    }
+
+    #[test]
+    fn parse_back() {
+        for answer in [
+            Answer::from(-12),
+            Answer::from("CMZ"),
+            Answer::from(vec!["##".to_string(), "..".to_string()]),
+            Answer::Unsolved,
+        ] {
+            assert_eq!(answer.to_string().parse::<Answer>().unwrap(), answer);
+        }
+    }
+}
@@ -0,0 +1,271 @@
+//! The accepted answers, kept next to the inputs in `data/<year>/<day>.answers`.
+//!
+//! The file has one line per solved part, lines starting with `#` are comments.
+//! A picture is written on the lines below its part, each indented by four spaces.
+//! A `?` after the part marks an answer that the site has not accepted yet, such as
+//! one stored by `verify --save`, which only guards against regressions:
+//! ```text
+//! 1: 24000
+//! 2?:
+//!     #..#
+//!     ####
+//! ```
+//! The same format holds the expected answers of the examples, see [`crate::examples`].
+
+use crate::{
+    answer::Answer,
+    error::ParseError,
+    input,
+    solution::{Part, Solution},
+};
+use anyhow::{bail, Context, Result};
+use std::{fmt, path::Path, str::FromStr};
+
+const PICTURE_INDENT: &str = "    ";
+
+pub fn answers_path(year: u32, day: u32) -> String {
+    input::data_path(year, day, "answers")
+}
+
+/// Known answers for both parts of one puzzle.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    parts: [Option<Answer>; 2],
+    /// Per part, whether the answer is only what a solver gave
+    unverified: [bool; 2],
+}
+
+/// How an answer compares to the stored one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Check {
+    Correct,
+    Wrong {
+        expected: Answer,
+    },
+    /// Nothing is stored for this part
+    Unknown,
+}
+
+impl Answers {
+    /// Loads the stored answers, which are empty when there is no file yet.
+    pub fn load(year: u32, day: u32) -> Result<Answers> {
+        Answers::load_from(Path::new(&answers_path(year, day)))
+    }
+
+    pub fn load_from(path: &Path) -> Result<Answers> {
+        match std::fs::read_to_string(path) {
+            Ok(text) => Ok(text
+                .parse()
+                .with_context(|| format!("could not read {}", path.display()))?),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(e).with_context(|| format!("could not read {}", path.display())),
+        }
+    }
+
+    pub fn save(&self, year: u32, day: u32) -> Result<()> {
+        self.save_to(Path::new(&answers_path(year, day)))
+    }
+
+    pub fn save_to(&self, path: &Path) -> Result<()> {
+        std::fs::write(path, self.to_string())
+            .with_context(|| format!("could not write {}", path.display()))
+    }
+
+    pub fn get(&self, part: Part) -> Option<&Answer> {
+        self.parts[part.number() as usize - 1].as_ref()
+    }
+
+    /// Stores an answer the site accepted.
+    pub fn set(&mut self, part: Part, answer: Answer) -> Result<()> {
+        self.store(part, answer, true)
+    }
+
+    /// Stores an answer the site has not accepted (yet).
+    pub fn set_unverified(&mut self, part: Part, answer: Answer) -> Result<()> {
+        self.store(part, answer, false)
+    }
+
+    fn store(&mut self, part: Part, answer: Answer, verified: bool) -> Result<()> {
+        if !answer.is_solved() {
+            bail!("cannot store {:?} as an answer", answer);
+        }
+        let i = part.number() as usize - 1;
+        self.parts[i] = Some(answer);
+        self.unverified[i] = !verified;
+        Ok(())
+    }
+
+    /// Whether the answer of `part` was accepted by the site.
+    pub fn is_verified(&self, part: Part) -> bool {
+        self.get(part).is_some() && !self.unverified[part.number() as usize - 1]
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parts.iter().all(Option::is_none)
+    }
+
+    pub fn check(&self, part: Part, answer: &Answer) -> Check {
+        match self.get(part) {
+            None => Check::Unknown,
+            Some(expected) if expected == answer => Check::Correct,
+            Some(expected) => Check::Wrong {
+                expected: expected.clone(),
+            },
+        }
+    }
+}
+
+impl FromStr for Answers {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut answers = Answers::default();
+        let mut lines = s.lines().peekable();
+        while let Some(line) = lines.next() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (part, verified, answer) = match line.split_once(':') {
+                Some(("1", answer)) => (Part::One, true, answer.trim()),
+                Some(("2", answer)) => (Part::Two, true, answer.trim()),
+                Some(("1?", answer)) => (Part::One, false, answer.trim()),
+                Some(("2?", answer)) => (Part::Two, false, answer.trim()),
+                _ => {
+                    return Err(ParseError::at(
+                        s,
+                        line,
+                        "expected \"1: <answer>\" or \"2: <answer>\", with a ? after an unverified part",
+                    ))
+                }
+            };
+            let answer = if answer.is_empty() {
+                let mut rows = Vec::new();
+                while let Some(row) = lines.next_if(|l| l.starts_with(PICTURE_INDENT)) {
+                    rows.push(row[PICTURE_INDENT.len()..].to_string());
+                }
+                if rows.is_empty() {
+                    return Err(ParseError::at(s, line, "expected an answer or a picture"));
+                }
+                Answer::Grid(rows)
+            } else {
+                answer.parse().unwrap()
+            };
+            answers
+                .store(part, answer, verified)
+                .map_err(|e| ParseError::at(s, line, e.to_string()))?;
+        }
+        Ok(answers)
+    }
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for part in Part::ALL {
+            let mark = if self.is_verified(part) { "" } else { "?" };
+            match self.get(part) {
+                Some(Answer::Grid(rows)) => {
+                    writeln!(f, "{}{}:", part, mark)?;
+                    for row in rows {
+                        writeln!(f, "{}{}", PICTURE_INDENT, row)?;
+                    }
+                }
+                Some(answer) => writeln!(f, "{}{}: {}", part, mark, answer)?,
+                None => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+/// One part checked by [`verify`].
+#[derive(Clone, Debug)]
+pub struct Verified {
+    pub part: Part,
+    pub answer: Answer,
+    pub check: Check,
+}
+
+/// Runs both parts of `solution` on its real input and compares them with the stored answers.
+pub fn verify(solution: &dyn Solution) -> Result<Vec<Verified>> {
+    let answers = Answers::load(solution.year(), solution.day())?;
+    let input = input::read_input(solution.year(), solution.day())?;
+    Part::ALL
+        .iter()
+        .map(|&part| {
+            let answer = solution.solve(part, &input)?;
+            Ok(Verified {
+                part,
+                check: answers.check(part, &answer),
+                answer,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_and_format() {
+        let answers: Answers = "1: 24000\n\n2: CMZ\n".parse().unwrap();
+        assert_eq!(answers.get(Part::One), Some(&Answer::from(24000)));
+        assert_eq!(answers.get(Part::Two), Some(&Answer::from("CMZ")));
+        assert_eq!(answers.to_string(), "1: 24000\n2: CMZ\n");
+
+        let only_second: Answers = "2: 7".parse().unwrap();
+        assert_eq!(only_second.get(Part::One), None);
+        assert_eq!(only_second.to_string(), "2: 7\n");
+
+        let err = "1: 3\n3: 4".parse::<Answers>().unwrap_err();
+        assert_eq!(err.line, 2);
+        assert!("1: -".parse::<Answers>().is_err());
+        assert!("1:\n".parse::<Answers>().is_err());
+        assert!("1 ?: 3".parse::<Answers>().is_err());
+    }
+
+    #[test]
+    fn unverified_answers() {
+        let text = "1: 24000\n2?:\n    ##..\n";
+        let mut answers: Answers = text.parse().unwrap();
+        assert!(answers.is_verified(Part::One));
+        assert!(!answers.is_verified(Part::Two));
+        assert_eq!(answers.to_string(), text);
+        // Unverified answers are still checked, to catch regressions
+        assert_eq!(
+            answers.check(Part::Two, &Answer::Grid(vec!["##..".into()])),
+            Check::Correct
+        );
+
+        answers.set_unverified(Part::One, Answer::from(7)).unwrap();
+        answers.set(Part::Two, Answer::from(8)).unwrap();
+        assert_eq!(answers.to_string(), "1?: 7\n2: 8\n");
+        assert!(!Answers::default().is_verified(Part::One));
+    }
+
+    #[test]
+    fn pictures_and_comments() {
+        let text = "# from the puzzle text\n1: 13140\n2:\n    ##..\n    ###.\n";
+        let answers: Answers = text.parse().unwrap();
+        assert_eq!(answers.get(Part::One), Some(&Answer::from(13140)));
+        assert_eq!(answers.get(Part::Two), Some(&"##..\n###.".parse().unwrap()));
+        assert_eq!(answers.to_string(), &text[23..]);
+    }
+
+    #[test]
+    fn check_answers() {
+        let mut answers = Answers::default();
+        assert!(answers.is_empty());
+        answers.set(Part::One, Answer::from(42)).unwrap();
+
+        assert_eq!(answers.check(Part::One, &Answer::from(42)), Check::Correct);
+        assert_eq!(
+            answers.check(Part::One, &Answer::from(41)),
+            Check::Wrong {
+                expected: Answer::from(42)
+            }
+        );
+        assert_eq!(answers.check(Part::Two, &Answer::from(1)), Check::Unknown);
+        assert!(answers.set(Part::Two, Answer::Unsolved).is_err());
+    }
+}
@@ -0,0 +1,211 @@
+use crate::{
+    answer::Answer,
+    solution::{Part, Solution},
+};
+use anyhow::Result;
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+/// How often to run each part.
+#[derive(Clone, Copy, Debug)]
+pub struct Options {
+    /// Untimed runs before measuring
+    pub warmup: usize,
+    pub runs: usize,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            warmup: 3,
+            runs: 20,
+        }
+    }
+}
+
+/// Timings of a number of runs.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(mut samples: Vec<Duration>) -> Option<Stats> {
+        if samples.is_empty() {
+            return None;
+        }
+        samples.sort();
+        let n = samples.len();
+        let median = if n.is_multiple_of(2) {
+            (samples[n / 2 - 1] + samples[n / 2]) / 2
+        } else {
+            samples[n / 2]
+        };
+        let mean = samples.iter().map(Duration::as_secs_f64).sum::<f64>() / n as f64;
+        let variance = samples
+            .iter()
+            .map(|d| (d.as_secs_f64() - mean).powi(2))
+            .sum::<f64>()
+            / n as f64;
+        Some(Stats {
+            runs: n,
+            min: samples[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        })
+    }
+}
+
+/// Times `f`, stopping at the first error.
+pub fn measure<T>(options: &Options, mut f: impl FnMut() -> Result<T>) -> Result<Stats> {
+    for _ in 0..options.warmup {
+        black_box(f()?);
+    }
+    let mut samples = Vec::with_capacity(options.runs);
+    for _ in 0..options.runs.max(1) {
+        let start = Instant::now();
+        black_box(f()?);
+        samples.push(start.elapsed());
+    }
+    Ok(Stats::from_samples(samples).unwrap())
+}
+
+/// The timings of one part of a puzzle.
+#[derive(Clone, Debug)]
+pub struct Report {
+    pub year: u32,
+    pub day: u32,
+    pub part: Part,
+    pub answer: Answer,
+    /// Parsing on its own, if the day has a separate parse step
+    pub parse: Option<Stats>,
+    /// The whole part, parsing included
+    pub solve: Stats,
+}
+
+/// Benchmarks `parts` of `solution` on `input`.
+pub fn bench(
+    solution: &dyn Solution,
+    input: &str,
+    parts: &[Part],
+    options: &Options,
+) -> Result<Vec<Report>> {
+    // The first call tells whether the day parses separately and doubles as warm-up.
+    let parse = match solution.parse(input) {
+        Some(_) => Some(measure(options, || solution.parse(input).unwrap())?),
+        None => None,
+    };
+    parts
+        .iter()
+        .map(|&part| {
+            Ok(Report {
+                year: solution.year(),
+                day: solution.day(),
+                part,
+                answer: solution.solve(part, input)?,
+                parse,
+                solve: measure(options, || solution.solve(part, input))?,
+            })
+        })
+        .collect()
+}
+
+/// Formats a duration with a unit that keeps it readable.
+pub fn format_duration(d: Duration) -> String {
+    let nanos = d.as_nanos();
+    if nanos < 1_000 {
+        format!("{} ns", nanos)
+    } else if nanos < 1_000_000 {
+        format!("{:.1} µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.1} ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.2} s", nanos as f64 / 1e9)
+    }
+}
+
+/// Lays the reports out as a table, one row per part.
+pub fn table(reports: &[Report]) -> String {
+    let mut out = format!(
+        "{:<4} {:>3} {:>4} {:>10} {:>10} {:>10} {:>10} {:>10}\n",
+        "year", "day", "part", "parse", "min", "median", "mean", "stddev"
+    );
+    for r in reports {
+        let parse = r
+            .parse
+            .map_or("-".to_string(), |p| format_duration(p.median));
+        out.push_str(&format!(
+            "{:<4} {:>3} {:>4} {:>10} {:>10} {:>10} {:>10} {:>10}\n",
+            r.year,
+            r.day,
+            r.part,
+            parse,
+            format_duration(r.solve.min),
+            format_duration(r.solve.median),
+            format_duration(r.solve.mean),
+            format_duration(r.solve.stddev),
+        ));
+    }
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use anyhow::bail;
+
+    #[test]
+    fn stats_of_samples() {
+        let samples = [4, 1, 3, 2].map(Duration::from_millis).to_vec();
+        let stats = Stats::from_samples(samples).unwrap();
+
+        assert_eq!(stats.runs, 4);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_micros(2500));
+        assert_eq!(stats.mean.as_micros(), 2500);
+        assert_eq!(stats.stddev.as_micros(), 1118);
+        assert_eq!(Stats::from_samples(vec![]), None);
+    }
+
+    #[test]
+    fn measure_runs_warmup_and_runs() {
+        let mut calls = 0;
+        let options = Options { warmup: 2, runs: 5 };
+        let stats = measure(&options, || {
+            calls += 1;
+            Ok(())
+        })
+        .unwrap();
+
+        assert_eq!(calls, 7);
+        assert_eq!(stats.runs, 5);
+        assert!(measure(&options, || -> Result<()> { bail!("broken") }).is_err());
+    }
+
+    #[test]
+    fn bench_a_registered_day() {
+        let solution = crate::solutions::find(2022, 7).unwrap();
+        let input = std::fs::read_to_string(crate::input::data_path(2022, 7, "example")).unwrap();
+        let options = Options { warmup: 0, runs: 2 };
+        let reports = bench(solution, &input, &Part::ALL, &options).unwrap();
+
+        assert_eq!(reports.len(), 2);
+        assert!(reports[0].parse.is_some());
+        assert_eq!(reports[0].answer, Answer::from(95437));
+        assert_eq!(table(&reports).lines().count(), 3);
+    }
+
+    #[test]
+    fn durations() {
+        assert_eq!(format_duration(Duration::from_nanos(15)), "15 ns");
+        assert_eq!(format_duration(Duration::from_micros(1500)), "1.5 ms");
+        assert_eq!(format_duration(Duration::from_secs(2)), "2.00 s");
+    }
+}
@@ -1,90 +0,0 @@
-use anyhow::Result;
-use std::path::Path;
-
-const DAY: u32 = 0;
-const YEAR: u32 = 2022;
-
-fn solve(input: &str) -> Result<String> {
-    Ok("".to_string())
-}
-
-fn solve_1(input: &str) -> Result<String> {
-    solve(input)
-}
-
-fn solve_2(input: &str) -> Result<String> {
-    solve(input)
-}
-
-fn input() -> String {
-    let path = format!("./data/{}/{}.input", YEAR, DAY);
-    let input_path = Path::new(&path);
-    let input = std::fs::read_to_string(input_path).unwrap();
-    input
-}
-
-fn main() {
-    let input = input();
-
-    let solve_first = solve_1(&input);
-    match solve_first {
-        Ok(res) => println!("Day {}, first puzzle: {}", DAY, res),
-        Err(e) => println!("{:?}", e),
-    }
-
-    let solve_second = solve_2(&input);
-    match solve_second {
-        Ok(res) => println!("Day {}, second puzzle: {}", DAY, res),
-        Err(e) => println!("{:?}", e),
-    }
-}
-
-#[cfg(test)]
-mod test {
-    use super::*;
-
-    fn example_input() -> String {
-        let path = format!("./data/{}/{}.example", YEAR, DAY);
-        let input_path = Path::new(&path);
-        let input = std::fs::read_to_string(input_path).unwrap();
-        input
-    }
-
-    #[test]
-    fn example_first() {
-        let input = example_input();
-
-        let result = "-";
-        let solve = solve_1(&input);
-
-        assert!(solve.is_ok());
-        assert_eq!(solve.unwrap(), result);
-    }
-
-    #[test]
-    fn multi_example_first() {
-        let inputs = [("", "-"), ("", "-"), ("", "-"), ("", "-")];
-        for (input, result) in inputs {
-            assert_eq!(solve_1(input).unwrap(), result);
-        }
-    }
-
-    #[test]
-    fn example_second() {
-        let input = example_input();
-
-        let result = "-";
-        let solve = solve_2(&input);
-
-        assert!(solve.is_ok());
-        assert_eq!(solve.unwrap(), result);
-    }
-
-    #[test]
-    fn multi_example_second() {
-        let inputs = [("", "-"), ("", "-"), ("", "-"), ("", "-")];
-        for (input, result) in inputs {
-            assert_eq!(solve_2(input).unwrap(), result);
-        }
-    }
-}
@@ -0,0 +1,298 @@
+//! Talks to the Advent of Code website.
+//!
+//! The session token is the `session` cookie of a logged in browser. It is taken
+//! from `AOC_SESSION`, or from a config file at `AOC_CONFIG` (by default
+//! `~/.config/aoc/config`) with lines like:
+//! ```text
+//! session = 53616c7465645f5f...
+//! base_url = https://adventofcode.com
+//! ```
+//! `AOC_BASE_URL` overrides the address of the site, so a local server can stand in for it.
+
+use crate::input;
+use anyhow::{anyhow, bail, Context, Result};
+use std::{path::Path, time::Duration};
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+const USER_AGENT: &str = "github.com/OscarMulder/AoC-2022";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Config {
+    pub base_url: String,
+    pub session: Option<String>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            base_url: BASE_URL.to_string(),
+            session: None,
+        }
+    }
+}
+
+impl Config {
+    /// Reads the config file, if there is one, and applies the environment on top of it.
+    pub fn load() -> Result<Config> {
+        let path = std::env::var("AOC_CONFIG").ok().or_else(|| {
+            std::env::var("HOME")
+                .ok()
+                .map(|home| format!("{}/.config/aoc/config", home))
+        });
+        let mut config = match path {
+            Some(path) if Path::new(&path).exists() => std::fs::read_to_string(&path)
+                .map_err(anyhow::Error::from)
+                .and_then(|text| Config::parse(&text))
+                .with_context(|| format!("could not read {}", path))?,
+            _ => Config::default(),
+        };
+        if let Ok(session) = std::env::var("AOC_SESSION") {
+            config.session = Some(session);
+        }
+        if let Ok(base_url) = std::env::var("AOC_BASE_URL") {
+            config.base_url = base_url;
+        }
+        Ok(config)
+    }
+
+    /// Parses `key = value` lines. Empty lines and lines starting with `#` are skipped.
+    pub fn parse(text: &str) -> Result<Config> {
+        let mut config = Config::default();
+        for line in text.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            match line.split_once('=').map(|(k, v)| (k.trim(), v.trim())) {
+                Some(("session", value)) => config.session = Some(value.to_string()),
+                Some(("base_url", value)) => config.base_url = value.to_string(),
+                _ => bail!("unknown setting {:?}", line),
+            }
+        }
+        Ok(config)
+    }
+}
+
+/// A logged in connection to the site.
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(config: &Config) -> Result<Client> {
+        let session = config
+            .session
+            .clone()
+            .ok_or_else(|| anyhow!("no session token, set AOC_SESSION or add it to the config"))?;
+        Ok(Client {
+            base_url: config.base_url.trim_end_matches('/').to_string(),
+            session,
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+        })
+    }
+
+    pub fn url(&self, path: &str) -> String {
+        format!("{}{}", self.base_url, path)
+    }
+
+    /// Sends a request with the session cookie and returns the body of the response.
+    pub fn request(&self, request: ureq::Request, form: Option<&[(&str, &str)]>) -> Result<String> {
+        let url = request.url().to_string();
+        let request = request.set("Cookie", &format!("session={}", self.session));
+        let response = match form {
+            Some(form) => request.send_form(form),
+            None => request.call(),
+        };
+        match response {
+            Ok(response) => Ok(response.into_string()?),
+            Err(ureq::Error::Status(400, _)) => {
+                bail!("{} rejected the session token, it may have expired", url)
+            }
+            Err(ureq::Error::Status(404, _)) => bail!("{} does not exist (yet)", url),
+            Err(ureq::Error::Status(code, _)) => bail!("{} answered with status {}", url, code),
+            Err(e) => Err(e).with_context(|| format!("could not reach {}", url)),
+        }
+    }
+
+    pub fn get(&self, path: &str) -> Result<String> {
+        self.request(self.agent.get(&self.url(path)), None)
+    }
+
+    pub fn post(&self, path: &str, form: &[(&str, &str)]) -> Result<String> {
+        self.request(self.agent.post(&self.url(path)), Some(form))
+    }
+
+    pub fn download_input(&self, year: u32, day: u32) -> Result<String> {
+        self.get(&format!("/{}/day/{}/input", year, day))
+    }
+}
+
+/// Returns the input of a puzzle from `data/`, downloading it first if it is not there.
+pub fn fetch_input(config: &Config, year: u32, day: u32) -> Result<String> {
+    fetch_input_to(config, year, day, Path::new(&input::input_path(year, day)))
+}
+
+/// The input cached at `path`, if there is one. An empty file, as left by
+/// `aoc new`, does not count as cached.
+pub fn cached_input(path: &Path) -> Option<String> {
+    let input = std::fs::read_to_string(path).ok()?;
+    (!input.is_empty()).then(|| input::normalize(&input))
+}
+
+/// Like [`fetch_input`], caching the input at `path`.
+///
+/// An input that is cached is never downloaded again.
+pub fn fetch_input_to(config: &Config, year: u32, day: u32, path: &Path) -> Result<String> {
+    if let Some(input) = cached_input(path) {
+        return Ok(input);
+    }
+    let client = Client::new(config)
+        .with_context(|| format!("{} is missing and cannot be downloaded", path.display()))?;
+    let input = client.download_input(year, day)?;
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)
+            .with_context(|| format!("could not create {}", dir.display()))?;
+    }
+    std::fs::write(path, &input).with_context(|| format!("could not write {}", path.display()))?;
+    Ok(input::normalize(&input))
+}
+
+/// A tiny HTTP server that stands in for the site in tests.
+#[cfg(test)]
+pub(crate) mod mock {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        sync::{Arc, Mutex},
+        thread,
+    };
+
+    /// One request as the server saw it.
+    #[derive(Clone, Debug, Default)]
+    pub struct Request {
+        /// e.g. `GET /2022/day/1/input`
+        pub line: String,
+        pub cookie: Option<String>,
+        pub body: String,
+    }
+
+    pub struct Server {
+        pub base_url: String,
+        pub requests: Arc<Mutex<Vec<Request>>>,
+    }
+
+    /// Answers every request with the next of `responses`, a status code and a body.
+    pub fn serve(responses: Vec<(u16, String)>) -> Server {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let seen = Arc::clone(&requests);
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let Ok((stream, _)) = listener.accept() else {
+                    return;
+                };
+                let mut reader = BufReader::new(stream);
+                let mut request = Request::default();
+                let mut length = 0;
+                let mut line = String::new();
+                while reader.read_line(&mut line).unwrap_or(0) > 0 && line != "\r\n" {
+                    let header = line.trim_end();
+                    if request.line.is_empty() {
+                        request.line = header.rsplit_once(' ').map_or(header, |(l, _)| l).into();
+                    } else if let Some((name, value)) = header.split_once(": ") {
+                        match name.to_ascii_lowercase().as_str() {
+                            "cookie" => request.cookie = Some(value.to_string()),
+                            "content-length" => length = value.parse().unwrap_or(0),
+                            _ => {}
+                        }
+                    }
+                    line.clear();
+                }
+                let mut content = vec![0; length];
+                reader.read_exact(&mut content).unwrap();
+                request.body = String::from_utf8(content).unwrap();
+                seen.lock().unwrap().push(request);
+
+                let response = format!(
+                    "HTTP/1.1 {} X\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                );
+                let _ = reader.into_inner().write_all(response.as_bytes());
+            }
+        });
+        Server { base_url, requests }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn config(base_url: &str, session: Option<&str>) -> Config {
+        Config {
+            base_url: base_url.to_string(),
+            session: session.map(String::from),
+        }
+    }
+
+    fn temp_input(name: &str) -> std::path::PathBuf {
+        let path =
+            std::env::temp_dir().join(format!("aoc-{}-{}/1.input", name, std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        path
+    }
+
+    #[test]
+    fn parse_config() {
+        let config =
+            Config::parse("# mine\nsession = abc\n\nbase_url=http://localhost:1\n").unwrap();
+        assert_eq!(config, self::config("http://localhost:1", Some("abc")));
+        assert_eq!(Config::parse("").unwrap(), Config::default());
+        assert!(Config::parse("sesion = abc").is_err());
+    }
+
+    #[test]
+    fn download_once_and_cache() {
+        let server = mock::serve(vec![(200, "1000\n2000\n".to_string())]);
+        let config = config(&server.base_url, Some("abc"));
+        let path = temp_input("fetch");
+        assert_eq!(cached_input(&path), None);
+
+        assert_eq!(
+            fetch_input_to(&config, 2022, 1, &path).unwrap(),
+            "1000\n2000\n"
+        );
+        assert_eq!(
+            fetch_input_to(&config, 2022, 1, &path).unwrap(),
+            "1000\n2000\n"
+        );
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "1000\n2000\n");
+        assert_eq!(cached_input(&path).as_deref(), Some("1000\n2000\n"));
+
+        let requests = server.requests.lock().unwrap();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].line, "GET /2022/day/1/input");
+        assert_eq!(requests[0].cookie.as_deref(), Some("session=abc"));
+    }
+
+    #[test]
+    fn failed_downloads() {
+        let server = mock::serve(vec![(400, "Please log in".to_string())]);
+        let path = temp_input("fetch-failed");
+
+        let err = fetch_input_to(&config(&server.base_url, Some("old")), 2022, 1, &path);
+        assert!(format!("{:?}", err.unwrap_err()).contains("session token"));
+        assert!(!path.exists());
+
+        let err = fetch_input_to(&config(&server.base_url, None), 2022, 1, &path);
+        assert!(format!("{:?}", err.unwrap_err()).contains("AOC_SESSION"));
+    }
+}
@@ -0,0 +1,165 @@
+use std::fmt;
+
+/// A parse failure that points at the offending spot in the puzzle input.
+///
+/// Errors are created against whatever slice a parser was looking at. Because
+/// those slices borrow from the full input, [`ParseError::relocate`] can later
+/// turn the position into the line and column of the complete puzzle input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub year: Option<u32>,
+    pub day: Option<u32>,
+    /// 1-based line number
+    pub line: usize,
+    /// 1-based column, counted in characters
+    pub column: usize,
+    /// The complete offending line
+    pub text: String,
+    pub message: String,
+    addr: usize,
+}
+
+impl ParseError {
+    /// Creates an error for position `at`, which must be a subslice of `input`.
+    ///
+    /// When `at` does not point into `input` the error is put at the start of it.
+    pub fn at(input: &str, at: &str, message: impl Into<String>) -> Self {
+        let offset = offset_in(input, at).unwrap_or(0);
+        let (line, column, text) = position(input, offset);
+        ParseError {
+            year: None,
+            day: None,
+            line,
+            column,
+            text: text.to_string(),
+            message: message.into(),
+            addr: input.as_ptr() as usize + offset,
+        }
+    }
+
+    /// Converts a nom error on `input` into a [`ParseError`].
+    pub fn from_nom(input: &str, err: nom::Err<nom::error::Error<&str>>) -> Self {
+        match err {
+            nom::Err::Incomplete(_) => {
+                ParseError::at(input, &input[input.len()..], "unexpected end of input")
+            }
+            nom::Err::Error(e) | nom::Err::Failure(e) => {
+                ParseError::at(input, e.input, format!("expected {:?}", e.code))
+            }
+        }
+    }
+
+    /// Recomputes the line and column relative to `input`, if the error points into it.
+    pub fn relocate(mut self, input: &str) -> Self {
+        let start = input.as_ptr() as usize;
+        if self.addr >= start && self.addr <= start + input.len() {
+            let (line, column, text) = position(input, self.addr - start);
+            self.line = line;
+            self.column = column;
+            self.text = text.to_string();
+        }
+        self
+    }
+
+    pub fn with_puzzle(mut self, year: u32, day: u32) -> Self {
+        self.year = Some(year);
+        self.day = Some(day);
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let (Some(year), Some(day)) = (self.year, self.day) {
+            write!(f, "{} day {}, ", year, day)?;
+        }
+        write!(
+            f,
+            "line {}, column {}: {}\n    {}\n    {:>width$}",
+            self.line,
+            self.column,
+            self.message,
+            self.text,
+            "^",
+            width = self.column
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Attaches the puzzle and the position in the full `input` to a [`ParseError`]
+/// somewhere in `err`. Other errors are returned as they are.
+pub fn locate(mut err: anyhow::Error, year: u32, day: u32, input: &str) -> anyhow::Error {
+    if let Some(parse_error) = err.downcast_mut::<ParseError>() {
+        *parse_error = parse_error.clone().relocate(input).with_puzzle(year, day);
+    }
+    err
+}
+
+fn offset_in(input: &str, at: &str) -> Option<usize> {
+    let start = input.as_ptr() as usize;
+    let addr = at.as_ptr() as usize;
+    if addr >= start && addr <= start + input.len() {
+        Some(addr - start)
+    } else {
+        None
+    }
+}
+
+fn position(input: &str, offset: usize) -> (usize, usize, &str) {
+    let before = &input[..offset];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    let line_end = input[offset..]
+        .find('\n')
+        .map_or(input.len(), |i| offset + i);
+    let column = input[line_start..offset].chars().count() + 1;
+    (line, column, &input[line_start..line_end])
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn position_of_subslice() {
+        let input = "first\nsecond line\nthird";
+        let err = ParseError::at(input, &input[13..], "bad");
+        assert_eq!((err.line, err.column), (2, 8));
+        assert_eq!(err.text, "second line");
+    }
+
+    #[test]
+    fn relocate_to_full_input() {
+        let input = "a\nb\nc x d\n";
+        let line = input.lines().nth(2).unwrap();
+        let err = ParseError::at(line, &line[2..], "bad");
+        assert_eq!((err.line, err.column), (1, 3));
+        let err = err.relocate(input);
+        assert_eq!((err.line, err.column), (3, 3));
+        assert_eq!(err.text, "c x d");
+    }
+
+    #[test]
+    fn display_points_at_column() {
+        let input = "move x from 1 to 2";
+        let err = ParseError::at(input, &input[5..], "expected a number").with_puzzle(2022, 5);
+        assert_eq!(
+            err.to_string(),
+            "2022 day 5, line 1, column 6: expected a number\n    move x from 1 to 2\n         ^"
+        );
+    }
+
+    #[test]
+    fn locate_through_context() {
+        let input = "1\n2\nthree\n";
+        let line = input.lines().nth(2).unwrap();
+        let err =
+            anyhow::Error::new(ParseError::at(line, line, "not a number")).context("while parsing");
+        let err = locate(err, 2022, 1, input);
+        let parse_error = err.downcast_ref::<ParseError>().unwrap();
+        assert_eq!(parse_error.line, 3);
+        assert_eq!(parse_error.day, Some(1));
+    }
+}
@@ -0,0 +1,215 @@
+//! The examples from the puzzle texts, with the answers they should give.
+//!
+//! Every `data/<year>/<day>.example*` file can have a sidecar with the same name
+//! plus `.answers`, in the format of [`crate::answers`]. The [`crate::solution!`]
+//! macro adds a test to every day that checks all of its examples, so adding an
+//! example only takes the two files. Every example is also run with the line
+//! endings, BOM and whitespace an input may pick up on its way to `data/`, to make
+//! sure [`input::normalize`] cleans them up.
+
+use crate::{
+    answer::Answer,
+    answers::{Answers, Check},
+    input,
+    solution::{Part, Solution},
+};
+use anyhow::{bail, Context, Result};
+use std::path::Path;
+
+/// One example input and the answers it is known to give.
+#[derive(Clone, Debug)]
+pub struct Example {
+    pub path: String,
+    pub input: String,
+    pub answers: Answers,
+}
+
+/// The examples of a puzzle, ordered by file name. Examples without answers are left out.
+pub fn examples(year: u32, day: u32) -> Result<Vec<Example>> {
+    let dir = format!("{}/{}", input::data_dir(), year);
+    let prefix = format!("{}.example", day);
+    let entries = match std::fs::read_dir(&dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e).with_context(|| format!("could not read {}", dir)),
+    };
+
+    let mut names = Vec::new();
+    for entry in entries {
+        let name = entry?.file_name().to_string_lossy().into_owned();
+        if name.starts_with(&prefix) && !name.ends_with(".answers") {
+            names.push(name);
+        }
+    }
+    names.sort();
+
+    let mut examples = Vec::new();
+    for name in names {
+        let path = format!("{}/{}", dir, name);
+        let answers_path = format!("{}.answers", path);
+        if !Path::new(&answers_path).exists() {
+            continue;
+        }
+        let answers = std::fs::read_to_string(&answers_path)
+            .with_context(|| format!("could not read {}", answers_path))?
+            .parse()
+            .with_context(|| format!("could not read {}", answers_path))?;
+        let input =
+            std::fs::read_to_string(&path).with_context(|| format!("could not read {}", path))?;
+        examples.push(Example {
+            path,
+            input,
+            answers,
+        });
+    }
+    Ok(examples)
+}
+
+/// The ways an input can be mangled on its way to `data/`, the first one being the
+/// file as it is.
+pub fn variants(input: &str) -> Vec<(&'static str, String)> {
+    let input = input.trim_end_matches('\n');
+    vec![
+        ("", format!("{}\n", input)),
+        ("with CRLF", format!("{}\r\n", input.replace('\n', "\r\n"))),
+        ("with a BOM", format!("\u{feff}{}\n", input)),
+        (
+            "with trailing spaces",
+            format!("{} \n", input.replace('\n', " \t\n")),
+        ),
+        ("without final newline", input.to_string()),
+        ("with extra newlines", format!("{}\n\n\n", input)),
+    ]
+}
+
+/// Runs every example of `solution` and fails with a list of all wrong answers.
+///
+/// Every variant of an example is normalized and run, only the first one that goes
+/// wrong is reported. Returns the number of parts that were checked.
+pub fn check(solution: &dyn Solution) -> Result<usize> {
+    let mut checked = 0;
+    let mut failures = Vec::new();
+    for example in examples(solution.year(), solution.day())? {
+        for part in Part::ALL {
+            if example.answers.get(part).is_none() {
+                continue;
+            }
+            checked += 1;
+            for (variant, input) in variants(&example.input) {
+                let path = match variant {
+                    "" => example.path.clone(),
+                    variant => format!("{} ({})", example.path, variant),
+                };
+                let answer = solution.solve(part, &input::normalize(&input));
+                let failure = match answer.map(|a| (example.answers.check(part, &a), a)) {
+                    Ok((Check::Correct, _)) => continue,
+                    Ok((Check::Wrong { expected }, answer)) => format!(
+                        "{}, part {}: expected {}, got {}",
+                        path,
+                        part,
+                        multiline(&expected),
+                        multiline(&answer)
+                    ),
+                    Ok((Check::Unknown, _)) => unreachable!("only parts with an answer are run"),
+                    Err(e) => format!("{}, part {}: {:#}", path, part, e),
+                };
+                failures.push(failure);
+                break;
+            }
+        }
+    }
+    if !failures.is_empty() {
+        bail!("{}", failures.join("\n"));
+    }
+    Ok(checked)
+}
+
+fn multiline(answer: &Answer) -> String {
+    match answer {
+        Answer::Grid(rows) => format!("\n{}\n", rows.join("\n")),
+        answer => answer.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn find_examples() {
+        let examples = examples(2022, 9).unwrap();
+        let paths: Vec<&str> = examples.iter().map(|e| e.path.as_str()).collect();
+        let expected = [
+            input::data_path(2022, 9, "example"),
+            input::data_path(2022, 9, "example2"),
+        ];
+        assert_eq!(paths, expected);
+        assert_eq!(examples[1].answers.get(Part::One), None);
+        assert_eq!(examples[1].answers.get(Part::Two), Some(&Answer::from(36)));
+
+        assert!(super::examples(2022, 99).unwrap().is_empty());
+        assert!(super::examples(1999, 1).unwrap().is_empty());
+    }
+
+    #[test]
+    fn report_wrong_answers() {
+        // Gets part 1 of 2022 day 1 wrong and fails part 2
+        struct Wrong;
+        impl Solution for Wrong {
+            fn year(&self) -> u32 {
+                2022
+            }
+            fn day(&self) -> u32 {
+                1
+            }
+            fn title(&self) -> &'static str {
+                "Wrong"
+            }
+            fn solve_1(&self, _input: &str) -> Result<Answer> {
+                Ok(Answer::from(1))
+            }
+            fn solve_2(&self, _input: &str) -> Result<Answer> {
+                bail!("broken")
+            }
+        }
+
+        let err = check(&Wrong).unwrap_err().to_string();
+        let path = input::data_path(2022, 1, "example");
+        assert!(err.contains(&format!("{}, part 1: expected 24000, got 1", path)));
+        assert!(err.contains(&format!("{}, part 2: broken", path)));
+        assert_eq!(err.lines().count(), 2);
+    }
+
+    #[test]
+    fn report_variant() {
+        // Fails part 1 on anything normalize should have cleaned up
+        struct Picky;
+        impl Solution for Picky {
+            fn year(&self) -> u32 {
+                2022
+            }
+            fn day(&self) -> u32 {
+                1
+            }
+            fn title(&self) -> &'static str {
+                "Picky"
+            }
+            fn solve_1(&self, input: &str) -> Result<Answer> {
+                if input.contains([' ', '\t', '\r', '\u{feff}']) || input.ends_with("\n\n") {
+                    bail!("not normalized")
+                }
+                Ok(Answer::from(24000))
+            }
+            fn solve_2(&self, _input: &str) -> Result<Answer> {
+                Ok(Answer::from(45000))
+            }
+        }
+        assert_eq!(check(&Picky).unwrap(), 2);
+
+        let variants = variants("a\nb\n");
+        assert_eq!(variants.len(), 6);
+        for (_, input) in &variants {
+            assert_eq!(input::normalize(input), "a\nb\n");
+        }
+    }
+}
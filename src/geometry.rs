@@ -0,0 +1,326 @@
+//! Points, directions and lines on a plane of whole numbers.
+//!
+//! y points down, as in the drawings of the puzzles, so [`Direction::Up`] lowers y.
+
+use crate::{error::ParseError, grid::Pos, input};
+use std::{
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+
+/// A point, or the vector between two points.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Coord {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Coord {
+    pub const ORIGIN: Coord = Coord { x: 0, y: 0 };
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Coord { x, y }
+    }
+
+    /// The sum of the distances along both axes.
+    pub fn manhattan(&self, other: Coord) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    /// The number of king moves between the points, diagonal steps included.
+    pub fn chebyshev(&self, other: Coord) -> i64 {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    /// Both components clamped to -1, 0 or 1, a single step in the same direction.
+    pub fn signum(&self) -> Coord {
+        Coord::new(self.x.signum(), self.y.signum())
+    }
+
+    pub fn step(&self, direction: Direction) -> Coord {
+        *self + direction.delta()
+    }
+
+    pub fn neighbours4(&self) -> impl Iterator<Item = Coord> {
+        let c = *self;
+        Direction::ORTHOGONAL.into_iter().map(move |d| c.step(d))
+    }
+
+    pub fn neighbours8(&self) -> impl Iterator<Item = Coord> {
+        let c = *self;
+        Direction::ALL.into_iter().map(move |d| c.step(d))
+    }
+
+    /// The position in a grid, if neither component is negative.
+    pub fn to_pos(&self) -> Option<Pos> {
+        Some((self.x.try_into().ok()?, self.y.try_into().ok()?))
+    }
+}
+
+impl From<Pos> for Coord {
+    fn from((x, y): Pos) -> Coord {
+        Coord::new(x as i64, y as i64)
+    }
+}
+
+impl Add for Coord {
+    type Output = Coord;
+
+    fn add(self, other: Coord) -> Coord {
+        Coord::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Coord {
+    type Output = Coord;
+
+    fn sub(self, other: Coord) -> Coord {
+        Coord::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl AddAssign for Coord {
+    fn add_assign(&mut self, other: Coord) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Coord {
+    fn sub_assign(&mut self, other: Coord) {
+        *self = *self - other;
+    }
+}
+
+impl Neg for Coord {
+    type Output = Coord;
+
+    fn neg(self) -> Coord {
+        Coord::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<i64> for Coord {
+    type Output = Coord;
+
+    fn mul(self, n: i64) -> Coord {
+        Coord::new(self.x * n, self.y * n)
+    }
+}
+
+/// Reads `x,y`.
+impl FromStr for Coord {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let (x, y) = text
+            .split_once(',')
+            .ok_or_else(|| ParseError::at(text, text, "expected \"<x>,<y>\""))?;
+        Ok(Coord::new(
+            input::parse_at(text, x.trim())?,
+            input::parse_at(text, y.trim())?,
+        ))
+    }
+}
+
+/// One of the four orthogonal or four diagonal directions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+    UpRight,
+    DownRight,
+    DownLeft,
+    UpLeft,
+}
+
+impl Direction {
+    pub const ORTHOGONAL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// Clockwise, starting at the top.
+    pub const ALL: [Direction; 8] = [
+        Direction::Up,
+        Direction::UpRight,
+        Direction::Right,
+        Direction::DownRight,
+        Direction::Down,
+        Direction::DownLeft,
+        Direction::Left,
+        Direction::UpLeft,
+    ];
+
+    /// Reads `U`, `D`, `L` and `R`, or the arrows `^`, `v`, `<` and `>`.
+    pub fn from_char(c: char) -> Option<Direction> {
+        match c {
+            'U' | '^' => Some(Direction::Up),
+            'R' | '>' => Some(Direction::Right),
+            'D' | 'v' => Some(Direction::Down),
+            'L' | '<' => Some(Direction::Left),
+            _ => None,
+        }
+    }
+
+    /// The step to take.
+    pub fn delta(&self) -> Coord {
+        match self {
+            Direction::Up => Coord::new(0, -1),
+            Direction::Right => Coord::new(1, 0),
+            Direction::Down => Coord::new(0, 1),
+            Direction::Left => Coord::new(-1, 0),
+            Direction::UpRight => Coord::new(1, -1),
+            Direction::DownRight => Coord::new(1, 1),
+            Direction::DownLeft => Coord::new(-1, 1),
+            Direction::UpLeft => Coord::new(-1, -1),
+        }
+    }
+
+    pub fn is_diagonal(&self) -> bool {
+        let delta = self.delta();
+        delta.x != 0 && delta.y != 0
+    }
+
+    /// A quarter turn clockwise.
+    pub fn turn_right(&self) -> Direction {
+        self.turn(2)
+    }
+
+    /// A quarter turn counterclockwise.
+    pub fn turn_left(&self) -> Direction {
+        self.turn(6)
+    }
+
+    pub fn opposite(&self) -> Direction {
+        self.turn(4)
+    }
+
+    /// Turns clockwise by `eighths` of a full turn.
+    fn turn(&self, eighths: usize) -> Direction {
+        let i = Direction::ALL.iter().position(|d| d == self).unwrap();
+        Direction::ALL[(i + eighths) % 8]
+    }
+}
+
+/// A straight line between two points, both included.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Segment {
+    pub from: Coord,
+    pub to: Coord,
+}
+
+impl Segment {
+    pub fn new(from: Coord, to: Coord) -> Self {
+        Segment { from, to }
+    }
+
+    pub fn is_horizontal(&self) -> bool {
+        self.from.y == self.to.y
+    }
+
+    pub fn is_vertical(&self) -> bool {
+        self.from.x == self.to.x
+    }
+
+    /// Whether the segment is at exactly 45 degrees.
+    pub fn is_diagonal(&self) -> bool {
+        let d = self.to - self.from;
+        d.x != 0 && d.x.abs() == d.y.abs()
+    }
+
+    /// The points on the segment, from `from` to `to`.
+    ///
+    /// Horizontal, vertical and 45 degree segments hit every point exactly.
+    /// Other segments are rasterized with Bresenham's algorithm, one point per
+    /// step along their longer axis.
+    pub fn points(&self) -> impl Iterator<Item = Coord> {
+        let d = self.to - self.from;
+        let (dx, dy) = (d.x.abs(), -d.y.abs());
+        let step = d.signum();
+        let mut current = self.from;
+        let mut error = dx + dy;
+        let mut remaining = dx.max(-dy) + 1;
+        std::iter::from_fn(move || {
+            if remaining == 0 {
+                return None;
+            }
+            remaining -= 1;
+            let point = current;
+            let doubled = 2 * error;
+            if doubled >= dy {
+                error += dy;
+                current.x += step.x;
+            }
+            if doubled <= dx {
+                error += dx;
+                current.y += step.y;
+            }
+            Some(point)
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn arithmetic_and_distances() {
+        let a = Coord::new(1, -2);
+        let b: Coord = "4, 2".parse().unwrap();
+        assert_eq!(b - a, Coord::new(3, 4));
+        assert_eq!(a + b * 2, Coord::new(9, 2));
+        assert_eq!(-a, Coord::new(-1, 2));
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!((b - a).signum(), Coord::new(1, 1));
+        assert_eq!(a.to_pos(), None);
+        assert_eq!(Coord::from((3, 4)).to_pos(), Some((3, 4)));
+        assert!("4;2".parse::<Coord>().is_err());
+    }
+
+    #[test]
+    fn directions() {
+        assert_eq!(Coord::ORIGIN.step(Direction::Up), Coord::new(0, -1));
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::UpLeft.opposite(), Direction::DownRight);
+        assert_eq!(Direction::from_char('v'), Some(Direction::Down));
+        assert!(Direction::DownLeft.is_diagonal());
+        assert_eq!(Coord::ORIGIN.neighbours8().count(), 8);
+        let sum = Coord::ORIGIN
+            .neighbours4()
+            .fold(Coord::ORIGIN, |sum, c| sum + c);
+        assert_eq!(sum, Coord::ORIGIN);
+    }
+
+    fn points(from: (i64, i64), to: (i64, i64)) -> Vec<(i64, i64)> {
+        Segment::new(Coord::new(from.0, from.1), Coord::new(to.0, to.1))
+            .points()
+            .map(|c| (c.x, c.y))
+            .collect()
+    }
+
+    #[test]
+    fn rasterize_segments() {
+        assert_eq!(points((3, 4), (1, 4)), [(3, 4), (2, 4), (1, 4)]);
+        assert_eq!(points((0, 9), (0, 7)), [(0, 9), (0, 8), (0, 7)]);
+        assert_eq!(points((9, 7), (7, 9)), [(9, 7), (8, 8), (7, 9)]);
+        assert_eq!(points((5, 5), (5, 5)), [(5, 5)]);
+        assert_eq!(
+            points((0, 0), (4, 2)),
+            [(0, 0), (1, 1), (2, 1), (3, 2), (4, 2)]
+        );
+        assert_eq!(
+            points((0, 0), (-1, -3)),
+            [(0, 0), (0, -1), (-1, -2), (-1, -3)]
+        );
+
+        let segment = Segment::new(Coord::new(9, 7), Coord::new(7, 9));
+        assert!(segment.is_diagonal() && !segment.is_horizontal() && !segment.is_vertical());
+    }
+}
@@ -0,0 +1,301 @@
+//! A rectangle of cells, as drawn by so many puzzles.
+
+use crate::{error::ParseError, geometry::Direction, input};
+use anyhow::{bail, Result};
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+/// A position in a grid, `(x, y)` with x to the right and y down from the top left.
+pub type Pos = (usize, usize);
+
+/// A rectangular grid, stored row by row.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid by calling `f` with the position of every cell.
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Pos) -> T) -> Grid<T> {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(&mut f)
+            .collect();
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Fails when the rows are not all as long.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Grid<T>> {
+        let width = rows.first().map_or(0, Vec::len);
+        if let Some((y, row)) = rows.iter().enumerate().find(|(_, r)| r.len() != width) {
+            bail!("row {} has {} cells, the first has {}", y, row.len(), width);
+        }
+        Ok(Grid {
+            width,
+            height: rows.len(),
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Parses a grid with one character per cell, mapped by `f`.
+    ///
+    /// Fails on characters `f` gives `None` for and on lines that are shorter or
+    /// longer than the first.
+    pub fn parse<F>(text: &str, f: F) -> Result<Grid<T>>
+    where
+        F: Fn(char) -> Option<T>,
+    {
+        let rows = input::grid(text, f)?;
+        let width = rows.first().map_or(0, Vec::len);
+        if let Some((line, row)) = text.lines().zip(&rows).find(|(_, r)| r.len() != width) {
+            let message = format!(
+                "expected {} cells like the first line, found {}",
+                width,
+                row.len()
+            );
+            return Err(ParseError::at(text, line, message).into());
+        }
+        Grid::from_rows(rows)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): Pos) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.1 * self.width + pos.0])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.contains(pos) {
+            Some(&mut self.cells[pos.1 * self.width + pos.0])
+        } else {
+            None
+        }
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// The position of the first cell, row by row, for which `f` holds.
+    pub fn position(&self, f: impl Fn(&T) -> bool) -> Option<Pos> {
+        self.iter().find(|(_, cell)| f(cell)).map(|(pos, _)| pos)
+    }
+
+    /// The position one step from `pos`, if it is still in the grid.
+    pub fn step(&self, (x, y): Pos, direction: Direction) -> Option<Pos> {
+        let delta = direction.delta();
+        let pos = (
+            x.checked_add_signed(delta.x as isize)?,
+            y.checked_add_signed(delta.y as isize)?,
+        );
+        self.contains(pos).then_some(pos)
+    }
+
+    /// The positions one step from `pos` in each of `directions`.
+    pub fn neighbours<'a>(
+        &'a self,
+        pos: Pos,
+        directions: &'a [Direction],
+    ) -> impl Iterator<Item = Pos> + 'a {
+        directions.iter().filter_map(move |&d| self.step(pos, d))
+    }
+
+    /// The up to 4 positions above, below and beside `pos`.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        self.neighbours(pos, &Direction::ORTHOGONAL)
+    }
+
+    /// The up to 8 positions around `pos`, diagonals included.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        self.neighbours(pos, &Direction::ALL)
+    }
+
+    /// The positions from `pos` up to the edge in `direction`, without `pos` itself.
+    pub fn ray(&self, pos: Pos, direction: Direction) -> impl Iterator<Item = Pos> + '_ {
+        std::iter::successors(self.step(pos, direction), move |&p| self.step(p, direction))
+    }
+
+    /// Row `y`, from left to right.
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Column `x`, from top to bottom.
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(x < self.width, "column {} is outside the grid", x);
+        (0..self.height).map(move |y| &self.cells[y * self.width + x])
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl DoubleEndedIterator<Item = impl DoubleEndedIterator<Item = &T>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T> {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Mirrors the grid in its diagonal, so rows become columns.
+    pub fn transpose(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |(x, y)| self[(y, x)].clone())
+    }
+
+    /// Turns the grid a quarter clockwise.
+    pub fn rotate_right(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |(x, y)| {
+            self[(y, self.height - 1 - x)].clone()
+        })
+    }
+
+    /// Turns the grid a quarter counterclockwise.
+    pub fn rotate_left(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |(x, y)| {
+            self[(self.width - 1 - y, x)].clone()
+        })
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        match self.get(pos) {
+            Some(cell) => cell,
+            None => panic!(
+                "{:?} is outside the {}x{} grid",
+                pos, self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        match self.get_mut(pos) {
+            Some(cell) => cell,
+            None => panic!("{:?} is outside the {}x{} grid", pos, width, height),
+        }
+    }
+}
+
+/// Draws the cells row by row, without anything in between.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        Grid::parse("abc\ndef\n", Some).unwrap()
+    }
+
+    #[test]
+    fn parse_rectangles() {
+        let grid = grid();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 'f');
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.position(|&c| c == 'e'), Some((1, 1)));
+        assert_eq!(grid.to_string(), "abc\ndef");
+
+        let err = Grid::parse("123\n45\n", |c| c.to_digit(10)).unwrap_err();
+        let err = err.downcast_ref::<ParseError>().unwrap();
+        assert_eq!((err.line, err.text.as_str()), (2, "45"));
+        let err = Grid::parse("123\n4x6\n", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!(err.downcast_ref::<ParseError>().unwrap().column, 2);
+        assert!(Grid::from_rows(vec![vec![1], vec![]]).is_err());
+    }
+
+    #[test]
+    fn neighbours_and_rays() {
+        let grid = grid();
+        let corner: Vec<Pos> = grid.neighbours4((0, 0)).collect();
+        assert_eq!(corner, [(1, 0), (0, 1)]);
+        assert_eq!(grid.neighbours8((1, 0)).count(), 5);
+        assert_eq!(grid.neighbours8((1, 1)).count(), 5);
+
+        let left: Vec<Pos> = grid.ray((2, 1), Direction::Left).collect();
+        assert_eq!(left, [(1, 1), (0, 1)]);
+        assert_eq!(
+            grid.ray((0, 1), Direction::UpRight).collect::<Vec<_>>(),
+            [(1, 0)]
+        );
+        assert_eq!(grid.ray((0, 0), Direction::Up).count(), 0);
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = grid();
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        assert_eq!(grid.column(1).rev().collect::<String>(), "eb");
+        assert_eq!(grid.rows().count(), 2);
+        let columns: Vec<String> = grid.columns().map(|c| c.collect()).collect();
+        assert_eq!(columns, ["ad", "be", "cf"]);
+    }
+
+    #[test]
+    fn transpose_and_rotate() {
+        let grid = grid();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_right().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_left().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.rotate_right().rotate_left(), grid);
+        assert_eq!(grid.map(|c| c.to_ascii_uppercase())[(0, 0)], 'A');
+    }
+}
@@ -0,0 +1,285 @@
+//! Loaders for the input shapes that keep coming back in the puzzles.
+//!
+//! All loaders work on the already read puzzle input, so they can be used from
+//! `solve_1`/`solve_2` as well as from tests that feed in inline examples.
+
+use crate::error::ParseError;
+use anyhow::{anyhow, Context, Result};
+use std::{io::Read, path::Path, str::FromStr};
+
+/// Directory with the files of all puzzles, `./data` unless `AOC_DATA_DIR` is set.
+pub fn data_dir() -> String {
+    std::env::var("AOC_DATA_DIR").unwrap_or_else(|_| "./data".to_string())
+}
+
+/// Path of a file kept for a puzzle, such as `./data/2022/1.input`.
+pub fn data_path(year: u32, day: u32, extension: &str) -> String {
+    format!("{}/{}/{}.{}", data_dir(), year, day, extension)
+}
+
+pub fn input_path(year: u32, day: u32) -> String {
+    data_path(year, day, "input")
+}
+
+pub fn read_input(year: u32, day: u32) -> Result<String> {
+    let path = input_path(year, day);
+    let input = std::fs::read_to_string(Path::new(&path))
+        .with_context(|| format!("could not read {}", path))?;
+    Ok(normalize(&input))
+}
+
+/// Cleans up an input that was saved on Windows or copied from a browser.
+///
+/// Drops a UTF-8 BOM, turns `\r\n` into `\n`, trims trailing whitespace from every
+/// line and ends the input with exactly one newline. Leading whitespace is kept,
+/// drawings like the stacks of 2022 day 5 depend on it.
+pub fn normalize(input: &str) -> String {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+    let mut normalized = String::with_capacity(input.len() + 1);
+    for line in input.lines() {
+        normalized.push_str(line.trim_end());
+        normalized.push('\n');
+    }
+    normalized.truncate(normalized.trim_end_matches('\n').len());
+    if !normalized.is_empty() {
+        normalized.push('\n');
+    }
+    normalized
+}
+
+/// Reads an input from the file at `path`, or from stdin when `path` is `-`.
+pub fn read_source(path: &str) -> Result<String> {
+    let input = if path == "-" {
+        let mut input = String::new();
+        std::io::stdin()
+            .read_to_string(&mut input)
+            .context("could not read stdin")?;
+        input
+    } else {
+        std::fs::read_to_string(path).with_context(|| format!("could not read {}", path))?
+    };
+    Ok(normalize(&input))
+}
+
+pub fn read_one_per_line<T>(path: &Path) -> Result<Vec<T>>
+where
+    T: FromStr,
+    T::Err: std::error::Error + Send + Sync + 'static,
+{
+    let input = std::fs::read_to_string(path)
+        .with_context(|| format!("could not read {}", path.display()))?;
+    lines(&normalize(&input))
+}
+
+/// Parses every line of `input` into a `T`.
+pub fn lines<T>(input: &str) -> Result<Vec<T>>
+where
+    T: FromStr,
+    T::Err: std::error::Error + Send + Sync + 'static,
+{
+    lines_with(input, |line| Ok(line.parse::<T>()?))
+}
+
+/// Maps every line of `input` with `f`, stopping at the first line that fails.
+///
+/// Errors are turned into a [`ParseError`] for the failing line. A [`ParseError`]
+/// returned by `f` keeps its column.
+pub fn lines_with<'a, T, F>(input: &'a str, f: F) -> Result<Vec<T>>
+where
+    F: Fn(&'a str) -> Result<T>,
+{
+    input
+        .lines()
+        .map(|line| {
+            f(line).map_err(|e| match e.downcast::<ParseError>() {
+                Ok(parse_error) => parse_error.relocate(input).into(),
+                Err(e) => ParseError::at(input, line, e.to_string()).into(),
+            })
+        })
+        .collect()
+}
+
+/// Splits `input` into groups separated by blank lines.
+pub fn groups(input: &str) -> Vec<&str> {
+    input
+        .split("\n\n")
+        .map(|group| group.trim_end_matches('\n'))
+        .filter(|group| !group.is_empty())
+        .collect()
+}
+
+/// Splits `input` into blank line separated groups and parses every line of a group.
+pub fn groups_of<T>(input: &str) -> Result<Vec<Vec<T>>>
+where
+    T: FromStr,
+    T::Err: std::error::Error + Send + Sync + 'static,
+{
+    groups(input)
+        .into_iter()
+        .enumerate()
+        .map(|(i, group)| lines(group).with_context(|| format!("in group {}", i + 1)))
+        .collect()
+}
+
+/// Splits `input` into `N` blank line separated sections, the last one holding
+/// the rest of the input.
+///
+/// Unlike [`groups`] the sections are not trimmed, so leading whitespace that
+/// carries meaning (like the crate drawing of 2022 day 5) is kept.
+pub fn sections<const N: usize>(input: &str) -> Result<[&str; N]> {
+    let sections: Vec<&str> = input.splitn(N, "\n\n").collect();
+    sections
+        .try_into()
+        .map_err(|s: Vec<&str>| anyhow!("expected {} sections, found {}", N, s.len()))
+}
+
+/// Maps every character of `input` with `f`, one row per line.
+pub fn grid<T, F>(input: &str, f: F) -> Result<Vec<Vec<T>>>
+where
+    F: Fn(char) -> Option<T>,
+{
+    lines_with(input, |line| {
+        line.char_indices()
+            .map(|(i, c)| {
+                f(c).ok_or_else(|| ParseError::at(line, &line[i..], format!("unexpected {:?}", c)))
+            })
+            .collect::<Result<_, _>>()
+            .map_err(Into::into)
+    })
+}
+
+pub fn char_grid(input: &str) -> Vec<Vec<char>> {
+    input.lines().map(|line| line.chars().collect()).collect()
+}
+
+pub fn digit_grid(input: &str) -> Result<Vec<Vec<u8>>> {
+    grid(input, |c| c.to_digit(10).map(|d| d as u8))
+}
+
+pub type Pair<T> = (T, T);
+
+/// Splits `s` once on `sep` and parses both halves.
+pub fn pair<T>(s: &str, sep: &str) -> Result<Pair<T>>
+where
+    T: FromStr,
+    T::Err: std::error::Error + Send + Sync + 'static,
+{
+    let Some((a, b)) = s.split_once(sep) else {
+        return Err(ParseError::at(s, &s[s.len()..], format!("expected {:?}", sep)).into());
+    };
+    Ok((parse_at(s, a)?, parse_at(s, b)?))
+}
+
+/// Parses lines of two `inner` separated pairs, separated by `outer`, like `2-4,6-8`.
+pub fn pairs<T>(input: &str, outer: &str, inner: &str) -> Result<Vec<(Pair<T>, Pair<T>)>>
+where
+    T: FromStr,
+    T::Err: std::error::Error + Send + Sync + 'static,
+{
+    lines_with(input, |line| {
+        let (first, second) = line.split_once(outer).ok_or_else(|| {
+            ParseError::at(line, &line[line.len()..], format!("expected {:?}", outer))
+        })?;
+        Ok((pair(first, inner)?, pair(second, inner)?))
+    })
+}
+
+/// Parses `s`, a subslice of `input`, reporting a failure at the position of `s`.
+pub fn parse_at<T>(input: &str, s: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: std::fmt::Display,
+{
+    s.parse()
+        .map_err(|e| ParseError::at(input, s, format!("{} in {:?}", e, s)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn parse_error(err: anyhow::Error) -> ParseError {
+        err.downcast::<ParseError>().unwrap()
+    }
+
+    #[test]
+    fn parse_lines() {
+        assert_eq!(lines::<u32>("1\n2\n3\n").unwrap(), vec![1, 2, 3]);
+        let err = parse_error(lines::<u32>("1\nx\n3").unwrap_err());
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.text, "x");
+    }
+
+    #[test]
+    fn normalize_inputs() {
+        let clean = "    [D]\n[N] [C]\n\nmove 1 from 2 to 1\n";
+        for messy in [
+            clean,
+            "\u{feff}    [D]\n[N] [C]\n\nmove 1 from 2 to 1\n",
+            "    [D]\r\n[N] [C]\r\n\r\nmove 1 from 2 to 1\r\n",
+            "    [D]    \n[N] [C]\t\n  \nmove 1 from 2 to 1 \n",
+            "    [D]\n[N] [C]\n\nmove 1 from 2 to 1",
+            "    [D]\n[N] [C]\n\nmove 1 from 2 to 1\n\n\n",
+        ] {
+            assert_eq!(normalize(messy), clean);
+        }
+        assert_eq!(normalize(""), "");
+        assert_eq!(normalize("\n\n"), "");
+    }
+
+    #[test]
+    fn read_from_file() {
+        let path = std::env::temp_dir().join(format!("aoc-read-source-{}", std::process::id()));
+        std::fs::write(&path, "1\r\n2").unwrap();
+        assert_eq!(read_source(path.to_str().unwrap()).unwrap(), "1\n2\n");
+        std::fs::remove_file(&path).unwrap();
+        assert!(read_source(&input_path(2022, 0)).is_err());
+    }
+
+    #[test]
+    fn read_one_per_line_is_strict() {
+        let path =
+            std::env::temp_dir().join(format!("aoc-read-one-per-line-{}", std::process::id()));
+        std::fs::write(&path, "1\n2\nthree\n").unwrap();
+        let err = parse_error(read_one_per_line::<u32>(&path).unwrap_err());
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(err.line, 3);
+    }
+
+    #[test]
+    fn parse_groups() {
+        let input = "1\n2\n\n3\n\n4\n5\n6\n";
+        assert_eq!(groups(input), vec!["1\n2", "3", "4\n5\n6"]);
+        assert_eq!(
+            groups_of::<u32>(input).unwrap(),
+            vec![vec![1, 2], vec![3], vec![4, 5, 6]]
+        );
+    }
+
+    #[test]
+    fn parse_sections() {
+        let [first, second] = sections::<2>("    [D]\n 1\n\nmove 1 from 2 to 1\n").unwrap();
+        assert_eq!(first, "    [D]\n 1");
+        assert_eq!(second, "move 1 from 2 to 1\n");
+        assert!(sections::<2>("no blank line").is_err());
+    }
+
+    #[test]
+    fn parse_grids() {
+        assert_eq!(digit_grid("12\n34").unwrap(), vec![vec![1, 2], vec![3, 4]]);
+        let err = parse_error(digit_grid("12\n3x").unwrap_err());
+        assert_eq!((err.line, err.column), (2, 2));
+        assert_eq!(char_grid("ab\ncd"), vec![vec!['a', 'b'], vec!['c', 'd']]);
+    }
+
+    #[test]
+    fn parse_pairs() {
+        assert_eq!(
+            pairs::<u32>("2-4,6-8\n2-3,4-5", ",", "-").unwrap(),
+            vec![((2, 4), (6, 8)), ((2, 3), (4, 5))]
+        );
+        let err = parse_error(pairs::<u32>("2-4,6-8\n2-4,6-x", ",", "-").unwrap_err());
+        assert_eq!((err.line, err.column), (2, 7));
+        assert!(pairs::<u32>("2-4;6-8", ",", "-").is_err());
+    }
+}
@@ -0,0 +1,19 @@
+pub mod answer;
+pub mod answers;
+pub mod bench;
+pub mod client;
+pub mod error;
+pub mod examples;
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod ocr;
+pub mod play;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
+pub mod search;
+pub mod sliding;
+pub mod solution;
+pub mod solutions;
+pub mod submit;
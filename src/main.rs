@@ -1,3 +1,621 @@
-fn main() {
-    println!("Hello, world From Main");
+use anyhow::{anyhow, Result};
+use aoc::{
+    answer::Answer,
+    answers::{self, Answers, Check},
+    bench,
+    client::{self, Config},
+    input, play,
+    report::{self, Record},
+    runner, scaffold,
+    sliding::{self, Node, PatternDatabase},
+    solution::{Part, Solution},
+    submit,
+};
+use clap::{Parser, Subcommand, ValueEnum};
+use std::{
+    path::Path,
+    time::{Duration, Instant},
+};
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum PartArg {
+    #[value(name = "1")]
+    First,
+    #[value(name = "2")]
+    Second,
+    All,
+}
+
+impl PartArg {
+    fn parts(&self) -> Vec<Part> {
+        match self {
+            PartArg::First => vec![Part::One],
+            PartArg::Second => vec![Part::Two],
+            PartArg::All => Part::ALL.to_vec(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum AlgorithmArg {
+    #[value(name = "astar")]
+    AStar,
+    #[value(name = "idastar")]
+    IdaStar,
+}
+
+impl AlgorithmArg {
+    fn algorithm(&self) -> sliding::Algorithm {
+        match self {
+            AlgorithmArg::AStar => sliding::Algorithm::AStar,
+            AlgorithmArg::IdaStar => sliding::Algorithm::IdaStar,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum HeuristicArg {
+    Manhattan,
+    LinearConflict,
+    /// Additive pattern databases, cached in data/sliding
+    Patterns,
+}
+
+impl HeuristicArg {
+    fn heuristic(&self, node: &Node) -> Result<sliding::Heuristic> {
+        Ok(match self {
+            HeuristicArg::Manhattan => sliding::Heuristic::Manhattan,
+            HeuristicArg::LinearConflict => sliding::Heuristic::LinearConflict,
+            HeuristicArg::Patterns => {
+                let (width, height) = (node.width(), node.height());
+                let path = format!("data/sliding/{}x{}.pdb", width, height);
+                let patterns = PatternDatabase::load_or_build(Path::new(&path), width, height)?;
+                sliding::Heuristic::Patterns(patterns)
+            }
+        })
+    }
+}
+
+/// How to print the results of runs and benchmarks.
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    Table,
+    /// One JSON object per part
+    Json,
+    Csv,
+}
+
+impl Format {
+    /// Prints `records` in this format, or `table` when it is [`Format::Table`].
+    fn print(&self, records: impl Iterator<Item = Record>, table: impl FnOnce() -> String) {
+        match self {
+            Format::Table => print!("{}", table()),
+            Format::Json => records.for_each(|r| println!("{}", r.to_json())),
+            Format::Csv => {
+                println!("{}", report::CSV_HEADER);
+                records.for_each(|r| println!("{}", r.to_csv()));
+            }
+        }
+    }
+}
+
+/// Runs the Advent of Code solution for a single day.
+#[derive(Parser)]
+#[command(
+    name = "aoc",
+    args_conflicts_with_subcommands = true,
+    subcommand_negates_reqs = true
+)]
+struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Year of the puzzle, e.g. 2022
+    #[arg(short, long, required_unless_present_any = ["list", "bench"])]
+    year: Option<u32>,
+
+    /// Day of the puzzle, 1 to 25
+    #[arg(short, long, required_unless_present_any = ["list", "bench"])]
+    day: Option<u32>,
+
+    /// Which part to run
+    #[arg(short, long, value_enum, default_value_t = PartArg::All)]
+    part: PartArg,
+
+    /// Read the input from this file instead of from data/, `-` reads stdin
+    #[arg(short, long, conflicts_with_all = ["list", "bench"])]
+    input: Option<String>,
+
+    /// Also draw how each part was solved, for days that can
+    #[arg(short, long, conflicts_with_all = ["list", "bench", "format"])]
+    show: bool,
+
+    /// List all implemented puzzles instead of running one
+    #[arg(short, long, conflicts_with_all = ["year", "day"])]
+    list: bool,
+
+    /// Time the solutions instead, for all days unless a year or day is given
+    #[arg(short, long)]
+    bench: bool,
+
+    /// Timed runs per part when benchmarking
+    #[arg(long, default_value_t = bench::Options::default().runs)]
+    runs: usize,
+
+    /// Untimed runs per part before benchmarking
+    #[arg(long, default_value_t = bench::Options::default().warmup)]
+    warmup: usize,
+
+    /// Output format of the answers or benchmarks
+    #[arg(long, value_enum, default_value_t = Format::Table)]
+    format: Format,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Checks the answers on the real inputs against data/<year>/<day>.answers
+    Verify {
+        #[arg(short, long)]
+        year: Option<u32>,
+
+        #[arg(short, long)]
+        day: Option<u32>,
+
+        /// Store the answers of parts that have none stored yet
+        #[arg(long)]
+        save: bool,
+    },
+    /// Creates and registers a new day from the template, with empty data files
+    New {
+        #[arg(short, long)]
+        year: u32,
+
+        #[arg(short, long)]
+        day: u32,
+
+        /// Title of the puzzle
+        #[arg(short, long, default_value = "-")]
+        title: String,
+    },
+    /// Submits the answer of one part, by default the one the solution gives
+    Submit {
+        #[arg(short, long)]
+        year: u32,
+
+        #[arg(short, long)]
+        day: u32,
+
+        #[arg(short, long, value_parser = clap::value_parser!(u32).range(1..=2))]
+        part: u32,
+
+        /// The answer to submit instead of running the solution
+        answer: Option<String>,
+    },
+    /// Runs every registered day, or those of one year or day, and sums up how they went
+    RunAll {
+        #[arg(short, long)]
+        year: Option<u32>,
+
+        #[arg(short, long)]
+        day: Option<u32>,
+
+        #[arg(short, long, value_enum, default_value_t = PartArg::All)]
+        part: PartArg,
+
+        /// Seconds a part may take before it is given up on
+        #[arg(short, long, default_value_t = 10.0)]
+        timeout: f64,
+
+        #[arg(long, value_enum, default_value_t = Format::Table)]
+        format: Format,
+    },
+    /// Solves a sliding puzzle and prints the moves of the blank as UDLR
+    Slide {
+        /// File with the board, one row per line with 0 or _ as the blank, `-` reads stdin
+        board: String,
+
+        #[arg(short, long, value_enum, default_value_t = AlgorithmArg::AStar)]
+        algorithm: AlgorithmArg,
+
+        #[arg(long, value_enum, default_value_t = HeuristicArg::LinearConflict)]
+        heuristic: HeuristicArg,
+
+        /// Solves with every heuristic and reports the boards each one expanded
+        #[arg(short, long, conflicts_with = "heuristic")]
+        compare: bool,
+    },
+    /// Plays a sliding puzzle in the terminal, with hints from the solver
+    Play {
+        /// File with the board, `-` reads stdin; without one a shuffled board is dealt
+        board: Option<String>,
+
+        /// Size of a shuffled board
+        #[arg(short, long, default_value_t = 3, conflicts_with = "board")]
+        width: usize,
+
+        #[arg(long, default_value_t = 3, conflicts_with = "board")]
+        height: usize,
+
+        /// Seed of a shuffled board, the same seed deals the same board
+        #[arg(short, long, default_value_t = 2022, conflicts_with = "board")]
+        seed: u64,
+
+        /// Heuristic for the hints, `patterns` keeps them quick on a 15-puzzle
+        #[arg(long, value_enum, default_value_t = HeuristicArg::LinearConflict)]
+        heuristic: HeuristicArg,
+    },
+    /// Downloads missing inputs, for every registered day of the year unless a day is given
+    Fetch {
+        #[arg(short, long)]
+        year: u32,
+
+        #[arg(short, long)]
+        day: Option<u32>,
+    },
+}
+
+/// The registered solutions, limited to `year` and `day` when given.
+fn selected(year: Option<u32>, day: Option<u32>) -> impl Iterator<Item = &'static dyn Solution> {
+    aoc::solutions::all()
+        .filter(move |s| year.is_none_or(|y| y == s.year()) && day.is_none_or(|d| d == s.day()))
+}
+
+fn list() {
+    for solution in aoc::solutions::all() {
+        println!(
+            "{} day {:>2}: {}",
+            solution.year(),
+            solution.day(),
+            solution.title()
+        );
+    }
+}
+
+/// The input of a day from `data/`, only loading the config to download it when
+/// it is not there yet.
+fn day_input(year: u32, day: u32) -> Result<String> {
+    match client::cached_input(Path::new(&input::input_path(year, day))) {
+        Some(input) => Ok(input),
+        None => client::fetch_input(&Config::load()?, year, day),
+    }
+}
+
+fn run(
+    solution: &dyn Solution,
+    parts: &[Part],
+    input: Option<&str>,
+    show: bool,
+    format: Format,
+) -> Result<bool> {
+    let input = match input {
+        Some(path) => input::read_source(path)?,
+        None => day_input(solution.year(), solution.day())?,
+    };
+    let mut ok = true;
+    let mut outcomes = Vec::new();
+    for &part in parts {
+        let name = format!("{} day {}, part {}", solution.year(), solution.day(), part);
+        let start = Instant::now();
+        let solved = solution.solve(part, &input);
+        let duration = start.elapsed();
+        let (answer, status) = match solved {
+            Ok(answer) if !answer.is_solved() => (Some(answer), runner::Status::Unsolved),
+            Ok(answer) => (Some(answer), runner::Status::Ok),
+            Err(e) => {
+                if format == Format::Table {
+                    println!("{} failed: {:?}", name, e);
+                }
+                ok = false;
+                (None, runner::Status::Error(format!("{:#}", e)))
+            }
+        };
+        match &answer {
+            _ if format != Format::Table => {}
+            Some(Answer::Grid(rows)) => println!("{}:\n{}", name, rows.join("\n")),
+            Some(Answer::Unsolved) => println!("{}: not solved yet", name),
+            Some(answer) => println!("{}: {}", name, answer),
+            None => {}
+        }
+        outcomes.push(runner::Outcome {
+            year: solution.year(),
+            day: solution.day(),
+            part,
+            answer,
+            duration,
+            status,
+        });
+        if show {
+            match solution.visualize(part, &input) {
+                Some(Ok(drawing)) => println!("{}", drawing),
+                Some(Err(e)) => {
+                    println!("{} cannot be drawn: {:?}", name, e);
+                    ok = false;
+                }
+                None => println!("{} has nothing to draw", name),
+            }
+        }
+    }
+    // The table was printed part by part already
+    if format != Format::Table {
+        format.print(outcomes.iter().map(Record::from), String::new);
+    }
+    Ok(ok)
+}
+
+fn run_bench(args: &Args) -> bool {
+    let options = bench::Options {
+        warmup: args.warmup,
+        runs: args.runs,
+    };
+    let parts = args.part.parts();
+    let mut reports = Vec::new();
+    let mut failed = Vec::new();
+    for solution in selected(args.year, args.day) {
+        let (year, day) = (solution.year(), solution.day());
+        let result = input::read_input(year, day)
+            .and_then(|input| bench::bench(solution, &input, &parts, &options));
+        match result {
+            Ok(r) => reports.extend(r),
+            Err(e) => {
+                eprintln!("{} day {} failed: {:?}", year, day, e);
+                failed.extend(
+                    parts
+                        .iter()
+                        .map(|&part| Record::failed(year, day, part, &e)),
+                );
+            }
+        }
+    }
+    let ok = failed.is_empty();
+    let records = reports.iter().map(Record::from).chain(failed);
+    args.format.print(records, || bench::table(&reports));
+    ok
+}
+
+fn run_all(
+    year: Option<u32>,
+    day: Option<u32>,
+    parts: &[Part],
+    timeout: Duration,
+    format: Format,
+) -> bool {
+    // Panics end up in the table, the default hook would print them halfway through.
+    std::panic::set_hook(Box::new(|_| {}));
+    let mut outcomes = Vec::new();
+    for solution in selected(year, day) {
+        eprintln!("running {} day {}", solution.year(), solution.day());
+        outcomes.extend(runner::run_day(solution, parts, timeout));
+    }
+    format.print(outcomes.iter().map(Record::from), || {
+        runner::table(&outcomes)
+    });
+    outcomes.iter().all(|o| o.status.is_ok())
+}
+
+fn verify_day(solution: &dyn Solution, save: bool) -> Result<bool> {
+    let mut stored = Answers::load(solution.year(), solution.day())?;
+    let mut ok = true;
+    let mut changed = false;
+    for verified in answers::verify(solution)? {
+        let name = format!(
+            "{} day {}, part {}",
+            solution.year(),
+            solution.day(),
+            verified.part
+        );
+        match verified.check {
+            _ if !verified.answer.is_solved() => println!("{}: not solved yet", name),
+            Check::Correct if stored.is_verified(verified.part) => println!("{}: ok", name),
+            Check::Correct => println!("{}: ok, not yet accepted by the site", name),
+            Check::Wrong { expected } => {
+                println!("{}: expected {}, got {}", name, expected, verified.answer);
+                ok = false;
+            }
+            Check::Unknown if save && verified.answer.submission().is_some() => {
+                println!("{}: saved {} as unverified", name, verified.answer);
+                stored.set_unverified(verified.part, verified.answer)?;
+                changed = true;
+            }
+            Check::Unknown => println!("{}: nothing stored", name),
+        }
+    }
+    if changed {
+        stored.save(solution.year(), solution.day())?;
+    }
+    Ok(ok)
+}
+
+fn verify(year: Option<u32>, day: Option<u32>, save: bool) -> bool {
+    let mut ok = true;
+    for solution in selected(year, day) {
+        let name = format!("{} day {}", solution.year(), solution.day());
+        if !Path::new(&input::input_path(solution.year(), solution.day())).exists() {
+            println!("{}: no input, skipped", name);
+            continue;
+        }
+        match verify_day(solution, save) {
+            Ok(day_ok) => ok &= day_ok,
+            Err(e) => {
+                println!("{} failed: {:?}", name, e);
+                ok = false;
+            }
+        }
+    }
+    ok
+}
+
+fn fetch(year: u32, day: Option<u32>) -> Result<()> {
+    let config = Config::load()?;
+    let days: Vec<u32> = match day {
+        Some(day) => vec![day],
+        None => aoc::solutions::for_year(year).map(|s| s.day()).collect(),
+    };
+    for day in days {
+        let path = input::input_path(year, day);
+        let cached = client::cached_input(Path::new(&path)).is_some();
+        client::fetch_input(&config, year, day)?;
+        if cached {
+            println!("{} was already there", path);
+        } else {
+            println!("downloaded {}", path);
+        }
+    }
+    Ok(())
+}
+
+fn submit(year: u32, day: u32, part: Part, answer: Option<String>) -> Result<()> {
+    let config = Config::load()?;
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            let solution = aoc::solutions::find(year, day)
+                .ok_or_else(|| anyhow!("no solution for {} day {}", year, day))?;
+            let input = client::fetch_input(&config, year, day)?;
+            let answer = solution.solve(part, &input)?;
+            answer
+                .submission()
+                .ok_or_else(|| anyhow!("{:?} cannot be submitted", answer))?
+        }
+    };
+    println!("{} day {}, part {}: submitting {}", year, day, part, answer);
+    let verdict = submit::submit(&config, year, day, part, &answer)?;
+    println!("{}", verdict);
+    Ok(())
+}
+
+fn slide(board: &str, algorithm: sliding::Algorithm, heuristic: HeuristicArg) -> Result<()> {
+    let node: Node = input::read_source(board)?.parse()?;
+    let solved = node.solve_with(algorithm, &heuristic.heuristic(&node)?)?;
+    if !node.verify(&solved.moves) {
+        return Err(anyhow!(
+            "the moves {:?} do not solve the board",
+            solved.moves
+        ));
+    }
+    println!("{}", solved.moves);
+    println!(
+        "{} moves, {} boards expanded",
+        solved.moves.len(),
+        solved.expanded
+    );
+    Ok(())
+}
+
+/// Solves the board with every heuristic and prints a table of the work each took.
+fn compare_heuristics(board: &str, algorithm: sliding::Algorithm) -> Result<()> {
+    let node: Node = input::read_source(board)?.parse()?;
+    println!(
+        "{:<16} {:>5} {:>12} {:>10}",
+        "heuristic", "moves", "expanded", "time"
+    );
+    for arg in HeuristicArg::value_variants() {
+        let heuristic = arg.heuristic(&node)?;
+        let start = Instant::now();
+        let solved = node.solve_with(algorithm, &heuristic)?;
+        println!(
+            "{:<16} {:>5} {:>12} {:>10}",
+            heuristic.name(),
+            solved.moves.len(),
+            solved.expanded,
+            bench::format_duration(start.elapsed())
+        );
+    }
+    Ok(())
+}
+
+fn play(
+    board: Option<&str>,
+    width: usize,
+    height: usize,
+    seed: u64,
+    heuristic: HeuristicArg,
+) -> Result<()> {
+    let node = match board {
+        Some(board) => input::read_source(board)?.parse()?,
+        None => Node::shuffled(width, height, seed)?,
+    };
+    let game = play::Game::new(node, heuristic.heuristic(&node)?)?;
+    play::play(game)
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+    match args.command {
+        Some(Command::Verify { year, day, save }) => {
+            if !verify(year, day, save) {
+                std::process::exit(1);
+            }
+            return Ok(());
+        }
+        Some(Command::New { year, day, title }) => {
+            for path in scaffold::new_day(Path::new("."), year, day, &title)? {
+                println!("wrote {}", path.display());
+            }
+            return Ok(());
+        }
+        Some(Command::RunAll {
+            year,
+            day,
+            part,
+            timeout,
+            format,
+        }) => {
+            let timeout = Duration::try_from_secs_f64(timeout)
+                .map_err(|_| anyhow!("{} is not a valid timeout", timeout))?;
+            if !run_all(year, day, &part.parts(), timeout, format) {
+                std::process::exit(1);
+            }
+            return Ok(());
+        }
+        Some(Command::Slide {
+            board,
+            algorithm,
+            heuristic,
+            compare,
+        }) => {
+            if compare {
+                return compare_heuristics(&board, algorithm.algorithm());
+            }
+            return slide(&board, algorithm.algorithm(), heuristic);
+        }
+        Some(Command::Play {
+            board,
+            width,
+            height,
+            seed,
+            heuristic,
+        }) => return play(board.as_deref(), width, height, seed, heuristic),
+        Some(Command::Fetch { year, day }) => return fetch(year, day),
+        Some(Command::Submit {
+            year,
+            day,
+            part,
+            answer,
+        }) => return submit(year, day, Part::from_number(part).unwrap(), answer),
+        None => {}
+    }
+    if args.list {
+        list();
+        return Ok(());
+    }
+    if args.bench {
+        if !run_bench(&args) {
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
+    let (year, day) = (args.year.unwrap(), args.day.unwrap());
+    let solution = aoc::solutions::find(year, day)
+        .ok_or_else(|| anyhow!("no solution for {} day {}", year, day))?;
+    if !run(
+        solution,
+        &args.part.parts(),
+        args.input.as_deref(),
+        args.show,
+        args.format,
+    )? {
+        std::process::exit(1);
+    }
+    Ok(())
 }
@@ -0,0 +1,179 @@
+//! Reads the block letters some puzzles draw instead of printing an answer.
+//!
+//! Two fonts are known: the 4x6 one (2016 day 8, 2019 day 8 and 11, 2021 day 13,
+//! 2022 day 10) and the 6x10 one (2018 day 10). Lit pixels are `#` or `█`,
+//! anything else is dark.
+
+use anyhow::{bail, Result};
+
+struct Font {
+    height: usize,
+    /// Columns taken by one letter, including the spacing after it
+    stride: usize,
+    glyphs: &'static [(char, &'static [&'static str])],
+}
+
+const SMALL: Font = Font {
+    height: 6,
+    stride: 5,
+    glyphs: &[
+        ('A', &[".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+        ('B', &["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+        ('C', &[".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+        ('E', &["####", "#...", "###.", "#...", "#...", "####"]),
+        ('F', &["####", "#...", "###.", "#...", "#...", "#..."]),
+        ('G', &[".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+        ('H', &["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+        ('I', &[".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+        ('J', &["..##", "...#", "...#", "...#", "#..#", ".##."]),
+        ('K', &["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+        ('L', &["#...", "#...", "#...", "#...", "#...", "####"]),
+        ('O', &[".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+        ('P', &["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+        ('R', &["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+        ('S', &[".###", "#...", "#...", ".##.", "...#", "###."]),
+        ('U', &["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+        ('Y', &["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+        ('Z', &["####", "...#", "..#.", ".#..", "#...", "####"]),
+    ],
+};
+
+#[rustfmt::skip]
+const LARGE: Font = Font {
+    height: 10,
+    stride: 8,
+    glyphs: &[
+        ('A', &["..##..", ".#..#.", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#", "#....#"]),
+        ('B', &["#####.", "#....#", "#....#", "#....#", "#####.", "#....#", "#....#", "#....#", "#....#", "#####."]),
+        ('C', &[".####.", "#....#", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#....#", ".####."]),
+        ('E', &["######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....", "#.....", "######"]),
+        ('F', &["######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....", "#.....", "#....."]),
+        ('G', &[".####.", "#....#", "#.....", "#.....", "#.....", "#..###", "#....#", "#....#", "#...##", ".###.#"]),
+        ('H', &["#....#", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#", "#....#", "#....#"]),
+        ('J', &["...###", "....#.", "....#.", "....#.", "....#.", "....#.", "....#.", "#...#.", "#...#.", ".###.."]),
+        ('K', &["#....#", "#...#.", "#..#..", "#.#...", "##....", "##....", "#.#...", "#..#..", "#...#.", "#....#"]),
+        ('L', &["#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "######"]),
+        ('N', &["#....#", "##...#", "##...#", "#.#..#", "#.#..#", "#..#.#", "#..#.#", "#...##", "#...##", "#....#"]),
+        ('P', &["#####.", "#....#", "#....#", "#....#", "#####.", "#.....", "#.....", "#.....", "#.....", "#....."]),
+        ('R', &["#####.", "#....#", "#....#", "#....#", "#####.", "#..#..", "#...#.", "#...#.", "#....#", "#....#"]),
+        ('X', &["#....#", "#....#", ".#..#.", ".#..#.", "..##..", "..##..", ".#..#.", ".#..#.", "#....#", "#....#"]),
+        ('Z', &["######", ".....#", ".....#", "....#.", "...#..", "..#...", ".#....", "#.....", "#.....", "######"]),
+    ],
+};
+
+fn lit(c: char) -> bool {
+    c == '#' || c == '█'
+}
+
+/// Reads the letters drawn in `rows`.
+///
+/// Blank rows above and below the letters are ignored, the font is picked by the
+/// height of what is left. Letters are expected to start in the first column.
+pub fn recognize<S: AsRef<str>>(rows: &[S]) -> Result<String> {
+    let pixels: Vec<Vec<bool>> = rows
+        .iter()
+        .map(|row| row.as_ref().chars().map(lit).collect())
+        .collect();
+    let first = pixels.iter().position(|row| row.contains(&true));
+    let last = pixels.iter().rposition(|row| row.contains(&true));
+    let (Some(first), Some(last)) = (first, last) else {
+        bail!("nothing is drawn");
+    };
+    let pixels = &pixels[first..=last];
+    let font = match pixels.len() {
+        6 => &SMALL,
+        10 => &LARGE,
+        height => bail!("no font is {} pixels high", height),
+    };
+
+    let width = pixels.iter().map(Vec::len).max().unwrap_or(0);
+    let at = |x: usize, y: usize| pixels[y].get(x).copied().unwrap_or(false);
+    let mut letters = String::new();
+    for start in (0..width).step_by(font.stride) {
+        if (start..start + font.stride).all(|x| (0..font.height).all(|y| !at(x, y))) {
+            continue;
+        }
+        let letter = font.glyphs.iter().find(|(_, glyph)| {
+            (0..font.stride).all(|dx| {
+                (0..font.height).all(|y| {
+                    let expected = glyph[y].chars().nth(dx).is_some_and(lit);
+                    at(start + dx, y) == expected
+                })
+            })
+        });
+        match letter {
+            Some((c, _)) => letters.push(*c),
+            None => bail!(
+                "unknown letter in columns {}..{}",
+                start,
+                start + font.stride
+            ),
+        }
+    }
+    Ok(letters)
+}
+
+/// Draws `text` in the 4x6 font, the inverse of [`recognize`].
+pub fn render(text: &str) -> Result<Vec<String>> {
+    render_with(&SMALL, text)
+}
+
+/// Draws `text` in the 6x10 font.
+pub fn render_large(text: &str) -> Result<Vec<String>> {
+    render_with(&LARGE, text)
+}
+
+fn render_with(font: &Font, text: &str) -> Result<Vec<String>> {
+    let mut rows = vec![String::new(); font.height];
+    for c in text.chars() {
+        let Some((_, glyph)) = font.glyphs.iter().find(|(g, _)| *g == c) else {
+            bail!("{:?} is not in the font", c);
+        };
+        for (row, line) in rows.iter_mut().zip(glyph.iter()) {
+            row.push_str(&format!("{:.<width$}", line, width = font.stride));
+        }
+    }
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn read_crt() {
+        let crt = [
+            "###..###..####..##..###...##..####..##..",
+            "#..#.#..#....#.#..#.#..#.#..#....#.#..#.",
+            "#..#.###....#..#....#..#.#..#...#..#..#.",
+            "###..#..#..#...#.##.###..####..#...####.",
+            "#....#..#.#....#..#.#.#..#..#.#....#..#.",
+            "#....###..####..###.#..#.#..#.####.#..#.",
+        ];
+        assert_eq!(recognize(&crt).unwrap(), "PBZGRAZA");
+    }
+
+    #[test]
+    fn ignores_blank_rows() {
+        let mut rows = vec!["".to_string()];
+        rows.extend(render("HI").unwrap());
+        rows.push("........".to_string());
+        assert_eq!(recognize(&rows).unwrap(), "HI");
+    }
+
+    #[test]
+    fn round_trip_both_fonts() {
+        let small: String = SMALL.glyphs.iter().map(|(c, _)| c).collect();
+        assert_eq!(recognize(&render(&small).unwrap()).unwrap(), small);
+        let large: String = LARGE.glyphs.iter().map(|(c, _)| c).collect();
+        assert_eq!(recognize(&render_large(&large).unwrap()).unwrap(), large);
+    }
+
+    #[test]
+    fn unknown_letters() {
+        let rows = ["####", "####", "####", "####", "####", "####"];
+        assert!(recognize(&rows).is_err());
+        assert!(recognize(&["#", "#"]).is_err());
+        assert!(recognize(&["...."]).is_err());
+    }
+}
@@ -0,0 +1,229 @@
+//! Playing sliding puzzles in the terminal, with the solver at hand for hints.
+//!
+//! The keys move the blank, like the `UDLR` moves of [`crate::sliding`], so a hint
+//! of `U` means pressing up.
+
+use crate::sliding::{Algorithm, Heuristic, Node};
+use anyhow::{bail, Result};
+use crossterm::{
+    cursor,
+    event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+    execute, queue,
+    style::Print,
+    terminal::{self, ClearType},
+};
+use std::io::{self, Write};
+
+/// What a key press asks for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Action {
+    /// Move the blank, as one of `UDLR`
+    Move(char),
+    Hint,
+    Restart,
+    Quit,
+}
+
+impl Action {
+    /// The action for a key: arrows or WASD, `h`, `r`, and `q` or Esc.
+    pub fn from_key(key: KeyEvent) -> Option<Action> {
+        if key.modifiers.contains(KeyModifiers::CONTROL) {
+            return (key.code == KeyCode::Char('c')).then_some(Action::Quit);
+        }
+        match key.code {
+            KeyCode::Up | KeyCode::Char('w' | 'W') => Some(Action::Move('U')),
+            KeyCode::Down | KeyCode::Char('s' | 'S') => Some(Action::Move('D')),
+            KeyCode::Left | KeyCode::Char('a' | 'A') => Some(Action::Move('L')),
+            KeyCode::Right | KeyCode::Char('d' | 'D') => Some(Action::Move('R')),
+            KeyCode::Char('h' | 'H' | '?') => Some(Action::Hint),
+            KeyCode::Char('r' | 'R') => Some(Action::Restart),
+            KeyCode::Char('q' | 'Q') | KeyCode::Esc => Some(Action::Quit),
+            _ => None,
+        }
+    }
+}
+
+/// A game in progress.
+pub struct Game {
+    start: Node,
+    node: Node,
+    moves: usize,
+    heuristic: Heuristic,
+    /// The rest of the last solution the solver found, while the player follows it
+    plan: Option<String>,
+    message: String,
+}
+
+impl Game {
+    /// A game that starts at `node`, with hints found by IDA* with `heuristic`.
+    pub fn new(node: Node, heuristic: Heuristic) -> Result<Game> {
+        if !node.is_solvable() {
+            bail!("this board cannot be solved");
+        }
+        Ok(Game {
+            start: node,
+            node,
+            moves: 0,
+            heuristic,
+            plan: None,
+            message: String::new(),
+        })
+    }
+
+    pub fn node(&self) -> Node {
+        self.node
+    }
+
+    /// The number of moves made since the start.
+    pub fn moves(&self) -> usize {
+        self.moves
+    }
+
+    pub fn is_solved(&self) -> bool {
+        self.node == self.node.solved()
+    }
+
+    /// Carries out `action`. Returns false when the player wants to stop.
+    pub fn act(&mut self, action: Action) -> Result<bool> {
+        self.message.clear();
+        match action {
+            Action::Move(_) if self.is_solved() => {
+                self.message = "Solved already, r starts over".to_string();
+            }
+            Action::Move(m) => match self.node.slide(m) {
+                Some(node) => {
+                    self.node = node;
+                    self.moves += 1;
+                    self.plan = self
+                        .plan
+                        .take()
+                        .and_then(|plan| plan.strip_prefix(m).map(str::to_string));
+                    if self.is_solved() {
+                        self.message = format!("Solved in {} moves!", self.moves);
+                    }
+                }
+                None => self.message = format!("The blank cannot move {}", m),
+            },
+            Action::Hint => {
+                self.message = match self.hint()? {
+                    Some(m) => format!("Hint: {}", m),
+                    None => "Nothing left to do".to_string(),
+                }
+            }
+            Action::Restart => {
+                self.node = self.start;
+                self.moves = 0;
+                self.plan = None;
+            }
+            Action::Quit => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    /// The next move of a shortest solution from here, if the board is not solved.
+    pub fn hint(&mut self) -> Result<Option<char>> {
+        if self.plan.is_none() {
+            let solved = self.node.solve_with(Algorithm::IdaStar, &self.heuristic)?;
+            self.plan = Some(solved.moves);
+        }
+        Ok(self.plan.as_ref().and_then(|plan| plan.chars().next()))
+    }
+
+    /// The screen: the board, the move counter, the last message and the keys.
+    pub fn render(&self) -> String {
+        format!(
+            "{}\nMoves: {}\n{}\n\narrows/WASD move the blank, h hint, r restart, q quit\n",
+            self.node, self.moves, self.message
+        )
+    }
+}
+
+/// Plays `game` in the terminal until the player quits.
+pub fn play(mut game: Game) -> Result<()> {
+    let mut stdout = io::stdout();
+    terminal::enable_raw_mode()?;
+    execute!(stdout, terminal::EnterAlternateScreen, cursor::Hide)?;
+    let result = run(&mut game, &mut stdout);
+    execute!(stdout, cursor::Show, terminal::LeaveAlternateScreen)?;
+    terminal::disable_raw_mode()?;
+    result?;
+    if game.is_solved() {
+        println!("Solved in {} moves", game.moves());
+    } else {
+        println!("Stopped after {} moves", game.moves());
+    }
+    Ok(())
+}
+
+fn run(game: &mut Game, stdout: &mut impl Write) -> Result<()> {
+    loop {
+        queue!(
+            stdout,
+            terminal::Clear(ClearType::All),
+            cursor::MoveTo(0, 0),
+            // Raw mode does not return the cursor to the start of the line
+            Print(game.render().replace('\n', "\r\n"))
+        )?;
+        stdout.flush()?;
+        if let Event::Key(key) = event::read()? {
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+            if let Some(action) = Action::from_key(key) {
+                if !game.act(action)? {
+                    return Ok(());
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn game(board: &str) -> Game {
+        Game::new(board.parse().unwrap(), Heuristic::LinearConflict).unwrap()
+    }
+
+    #[test]
+    fn keys() {
+        let key = |code| Action::from_key(KeyEvent::new(code, KeyModifiers::NONE));
+        assert_eq!(key(KeyCode::Up), Some(Action::Move('U')));
+        assert_eq!(key(KeyCode::Char('a')), Some(Action::Move('L')));
+        assert_eq!(key(KeyCode::Char('h')), Some(Action::Hint));
+        assert_eq!(key(KeyCode::Esc), Some(Action::Quit));
+        assert_eq!(key(KeyCode::Char('x')), None);
+        let ctrl_c = KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL);
+        assert_eq!(Action::from_key(ctrl_c), Some(Action::Quit));
+    }
+
+    #[test]
+    fn play_with_hints() {
+        let mut game = game("1 2 3\n4 0 6\n7 5 8");
+        assert!(game.act(Action::Move('X')).unwrap());
+        assert!(game.render().contains("cannot move X"));
+        assert_eq!(game.moves(), 0);
+
+        // Following the hints solves the board in the fewest moves
+        while let Some(m) = game.hint().unwrap() {
+            game.act(Action::Move(m)).unwrap();
+        }
+        assert!(game.is_solved());
+        assert_eq!(game.moves(), 2);
+        assert!(game.render().contains("Solved in 2 moves!"));
+        game.act(Action::Move('U')).unwrap();
+        assert_eq!(game.moves(), 2);
+
+        game.act(Action::Restart).unwrap();
+        assert_eq!((game.moves(), game.is_solved()), (0, false));
+        // A detour is undone by the next hint
+        game.act(Action::Move('U')).unwrap();
+        assert_eq!(game.hint().unwrap(), Some('D'));
+        game.act(Action::Hint).unwrap();
+        assert!(game.render().contains("Hint: D"));
+        assert!(!game.act(Action::Quit).unwrap());
+
+        assert!(Game::new("2 1 3\n4 5 6\n7 8 0".parse().unwrap(), Heuristic::Manhattan).is_err());
+    }
+}
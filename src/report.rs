@@ -0,0 +1,155 @@
+//! Results of runs and benchmarks as JSON lines or CSV, for scripts to read.
+//!
+//! Both formats have the same fields: year, day, part, answer, status, duration
+//! (in seconds) and error. A picture is a list of rows in JSON and its rows joined
+//! by newlines in CSV.
+
+use crate::{
+    answer::{json_string, Answer},
+    bench,
+    runner::Outcome,
+    solution::Part,
+};
+use std::time::Duration;
+
+pub const CSV_HEADER: &str = "year,day,part,answer,status,duration,error";
+
+/// One part of one puzzle.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Record {
+    pub year: u32,
+    pub day: u32,
+    pub part: Part,
+    pub answer: Option<Answer>,
+    pub status: &'static str,
+    pub duration: Duration,
+    pub error: Option<String>,
+}
+
+impl Record {
+    /// A part that could not be run at all.
+    pub fn failed(year: u32, day: u32, part: Part, error: &anyhow::Error) -> Record {
+        Record {
+            year,
+            day,
+            part,
+            answer: None,
+            status: "error",
+            duration: Duration::ZERO,
+            error: Some(format!("{:#}", error)),
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        format!(
+            "{{\"year\":{},\"day\":{},\"part\":{},\"answer\":{},\"status\":{},\"duration\":{},\"error\":{}}}",
+            self.year,
+            self.day,
+            self.part,
+            self.answer.as_ref().map_or("null".to_string(), Answer::to_json),
+            json_string(self.status),
+            self.duration.as_secs_f64(),
+            self.error.as_deref().map_or("null".to_string(), json_string),
+        )
+    }
+
+    pub fn to_csv(&self) -> String {
+        let answer = match &self.answer {
+            Some(Answer::Unsolved) | None => String::new(),
+            Some(answer) => answer.to_string(),
+        };
+        format!(
+            "{},{},{},{},{},{},{}",
+            self.year,
+            self.day,
+            self.part,
+            csv_field(&answer),
+            self.status,
+            self.duration.as_secs_f64(),
+            csv_field(self.error.as_deref().unwrap_or_default()),
+        )
+    }
+}
+
+impl From<&Outcome> for Record {
+    fn from(outcome: &Outcome) -> Record {
+        Record {
+            year: outcome.year,
+            day: outcome.day,
+            part: outcome.part,
+            answer: outcome.answer.clone(),
+            status: outcome.status.name(),
+            duration: outcome.duration,
+            error: outcome.message(),
+        }
+    }
+}
+
+/// A benchmark, timed by its median.
+impl From<&bench::Report> for Record {
+    fn from(report: &bench::Report) -> Record {
+        Record {
+            year: report.year,
+            day: report.day,
+            part: report.part,
+            answer: Some(report.answer.clone()),
+            status: if report.answer.is_solved() {
+                "ok"
+            } else {
+                "unsolved"
+            },
+            duration: report.solve.median,
+            error: None,
+        }
+    }
+}
+
+/// Quotes a CSV field when it needs to be.
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn record(answer: Answer, error: Option<&str>) -> Record {
+        Record {
+            year: 2022,
+            day: 10,
+            part: Part::Two,
+            answer: Some(answer),
+            status: "wrong",
+            duration: Duration::from_micros(1500),
+            error: error.map(String::from),
+        }
+    }
+
+    #[test]
+    fn json_lines() {
+        let wrong = record(Answer::from("PBZ\"GRAZA"), Some("expected PBZGRAZA"));
+        assert_eq!(
+            wrong.to_json(),
+            r#"{"year":2022,"day":10,"part":2,"answer":"PBZ\"GRAZA","status":"wrong","duration":0.0015,"error":"expected PBZGRAZA"}"#
+        );
+        let picture = record(Answer::from(vec!["#.".to_string(), ".#".to_string()]), None);
+        assert!(picture.to_json().contains(
+            r##""answer":["#.",".#"],"status":"wrong","duration":0.0015,"error":null}"##
+        ));
+    }
+
+    #[test]
+    fn csv_rows() {
+        let wrong = record(Answer::from(13140), Some("expected 1, or \"2\""));
+        assert_eq!(
+            wrong.to_csv(),
+            r#"2022,10,2,13140,wrong,0.0015,"expected 1, or ""2""""#
+        );
+        let picture = record(Answer::from(vec!["#.".to_string(), ".#".to_string()]), None);
+        assert_eq!(picture.to_csv(), "2022,10,2,\"#.\n.#\",wrong,0.0015,");
+    }
+}
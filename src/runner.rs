@@ -0,0 +1,277 @@
+//! Runs many puzzles in one go.
+//!
+//! Every part runs on a thread of its own, so a part that panics or never finishes
+//! cannot take the others down. A part that times out is left running in the
+//! background, there is no way to stop a thread from the outside.
+
+use crate::{
+    answer::Answer,
+    answers::{Answers, Check},
+    bench::format_duration,
+    input,
+    solution::{Part, Solution},
+};
+use std::{
+    any::Any,
+    fmt,
+    panic::{self, AssertUnwindSafe},
+    sync::{mpsc, Arc},
+    thread,
+    time::{Duration, Instant},
+};
+
+/// How a part went.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Status {
+    /// Solved, and matching the stored answer if there is one
+    Ok,
+    Unsolved,
+    Wrong {
+        expected: Answer,
+    },
+    Error(String),
+    Panic(String),
+    Timeout,
+}
+
+impl Status {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Status::Ok => "ok",
+            Status::Unsolved => "unsolved",
+            Status::Wrong { .. } => "wrong",
+            Status::Error(_) => "error",
+            Status::Panic(_) => "panic",
+            Status::Timeout => "timeout",
+        }
+    }
+
+    /// Whether the run as a whole should still count as a success.
+    pub fn is_ok(&self) -> bool {
+        matches!(self, Status::Ok | Status::Unsolved)
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(self.name())
+    }
+}
+
+/// The result of running one part.
+#[derive(Clone, Debug)]
+pub struct Outcome {
+    pub year: u32,
+    pub day: u32,
+    pub part: Part,
+    /// `None` when the part did not give an answer
+    pub answer: Option<Answer>,
+    pub duration: Duration,
+    pub status: Status,
+}
+
+impl Outcome {
+    /// The error, panic or mismatch that made the part fail.
+    pub fn message(&self) -> Option<String> {
+        match &self.status {
+            Status::Ok | Status::Unsolved => None,
+            Status::Wrong { expected } => Some(format!("expected {}", expected)),
+            Status::Error(message) | Status::Panic(message) => Some(message.clone()),
+            Status::Timeout => Some(format!(
+                "did not finish within {}",
+                format_duration(self.duration)
+            )),
+        }
+    }
+}
+
+/// Runs `parts` of `solution` on its input in `data/`, checking them against the
+/// stored answers. A part gets at most `timeout` to finish.
+pub fn run_day(solution: &'static dyn Solution, parts: &[Part], timeout: Duration) -> Vec<Outcome> {
+    let (year, day) = (solution.year(), solution.day());
+    let loaded = input::read_input(year, day)
+        .and_then(|input| Ok((Arc::<str>::from(input), Answers::load(year, day)?)));
+    parts
+        .iter()
+        .map(|&part| match &loaded {
+            Ok((input, answers)) => run_part(solution, part, Arc::clone(input), answers, timeout),
+            Err(e) => Outcome {
+                year,
+                day,
+                part,
+                answer: None,
+                duration: Duration::ZERO,
+                status: Status::Error(format!("{:#}", e)),
+            },
+        })
+        .collect()
+}
+
+/// Runs one part on a thread of its own.
+pub fn run_part(
+    solution: &'static dyn Solution,
+    part: Part,
+    input: Arc<str>,
+    answers: &Answers,
+    timeout: Duration,
+) -> Outcome {
+    let (sender, receiver) = mpsc::channel();
+    let start = Instant::now();
+    let spawned = thread::Builder::new()
+        .name(format!(
+            "{} day {} part {}",
+            solution.year(),
+            solution.day(),
+            part
+        ))
+        .spawn(move || {
+            let start = Instant::now();
+            let result = panic::catch_unwind(AssertUnwindSafe(|| solution.solve(part, &input)));
+            let _ = sender.send((result, start.elapsed()));
+        });
+
+    let (answer, duration, status) = match spawned {
+        Err(e) => (None, Duration::ZERO, Status::Error(e.to_string())),
+        Ok(_) => match receiver.recv_timeout(timeout) {
+            Ok((Ok(Ok(answer)), duration)) => {
+                let status = match answers.check(part, &answer) {
+                    _ if !answer.is_solved() => Status::Unsolved,
+                    Check::Wrong { expected } => Status::Wrong { expected },
+                    Check::Correct | Check::Unknown => Status::Ok,
+                };
+                (Some(answer), duration, status)
+            }
+            Ok((Ok(Err(e)), duration)) => (None, duration, Status::Error(format!("{:#}", e))),
+            Ok((Err(payload), duration)) => {
+                (None, duration, Status::Panic(panic_message(&*payload)))
+            }
+            Err(mpsc::RecvTimeoutError::Timeout) => (None, start.elapsed(), Status::Timeout),
+            Err(mpsc::RecvTimeoutError::Disconnected) => (
+                None,
+                start.elapsed(),
+                Status::Panic("the thread stopped without an answer".to_string()),
+            ),
+        },
+    };
+    Outcome {
+        year: solution.year(),
+        day: solution.day(),
+        part,
+        answer,
+        duration,
+        status,
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "panicked".to_string()
+    }
+}
+
+/// Lays the outcomes out as a table, one row per part. Failed parts show why
+/// instead of an answer.
+pub fn table(outcomes: &[Outcome]) -> String {
+    let mut out = format!(
+        "{:<4} {:>3} {:>4} {:<8} {:>10}  {}\n",
+        "year", "day", "part", "status", "time", "answer"
+    );
+    for o in outcomes {
+        let answer = match (&o.answer, o.message()) {
+            (Some(Answer::Grid(rows)), _) => format!("picture of {} rows", rows.len()),
+            (Some(answer), Some(message)) => format!("{}, {}", answer, message),
+            (Some(answer), None) => answer.to_string(),
+            (None, message) => message.unwrap_or_default(),
+        };
+        out.push_str(&format!(
+            "{:<4} {:>3} {:>4} {:<8} {:>10}  {}\n",
+            o.year,
+            o.day,
+            o.part,
+            o.status,
+            format_duration(o.duration),
+            answer.lines().next().unwrap_or_default(),
+        ));
+    }
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use anyhow::{bail, Result};
+
+    // Fakes 2022 day 1, which has an input and stored answers
+    struct Broken;
+    impl Solution for Broken {
+        fn year(&self) -> u32 {
+            2022
+        }
+        fn day(&self) -> u32 {
+            1
+        }
+        fn title(&self) -> &'static str {
+            "Broken"
+        }
+        fn solve_1(&self, _input: &str) -> Result<Answer> {
+            panic!("I die.")
+        }
+        fn solve_2(&self, _input: &str) -> Result<Answer> {
+            thread::sleep(Duration::from_secs(5));
+            Ok(Answer::Unsolved)
+        }
+    }
+
+    struct Wrong;
+    impl Solution for Wrong {
+        fn year(&self) -> u32 {
+            2022
+        }
+        fn day(&self) -> u32 {
+            1
+        }
+        fn title(&self) -> &'static str {
+            "Wrong"
+        }
+        fn solve_1(&self, _input: &str) -> Result<Answer> {
+            Ok(Answer::from(1))
+        }
+        fn solve_2(&self, _input: &str) -> Result<Answer> {
+            bail!("broken")
+        }
+    }
+
+    #[test]
+    fn isolate_panics_and_timeouts() {
+        let outcomes = run_day(&Broken, &Part::ALL, Duration::from_millis(100));
+        assert_eq!(outcomes[0].status, Status::Panic("I die.".to_string()));
+        assert_eq!(outcomes[1].status, Status::Timeout);
+        assert!(outcomes[1].duration >= Duration::from_millis(100));
+        assert!(outcomes.iter().all(|o| o.answer.is_none()));
+    }
+
+    #[test]
+    fn check_stored_answers() {
+        let timeout = Duration::from_secs(10);
+        let outcomes = run_day(&Wrong, &Part::ALL, timeout);
+        assert!(matches!(outcomes[0].status, Status::Wrong { .. }));
+        assert_eq!(outcomes[0].answer, Some(Answer::from(1)));
+        assert_eq!(outcomes[1].status, Status::Error("broken".to_string()));
+
+        let solution = crate::solutions::find(2022, 1).unwrap();
+        let outcomes = run_day(solution, &[Part::One], timeout);
+        assert_eq!(outcomes[0].status, Status::Ok);
+
+        let table = table(&outcomes);
+        assert_eq!(table.lines().count(), 2);
+        assert!(table
+            .lines()
+            .nth(1)
+            .unwrap()
+            .starts_with("2022   1    1 ok"));
+    }
+}
@@ -0,0 +1,208 @@
+//! Sets up a new day from `src/solutions/template.rs`.
+
+use anyhow::{bail, Context, Result};
+use std::path::{Path, PathBuf};
+
+const TEMPLATE: &str = include_str!("solutions/template.rs");
+
+/// Creates the solution file and the data stubs for a day and registers it.
+///
+/// `root` is the root of the repository. A day that already has a solution is
+/// never overwritten, data files that already exist are left alone. Returns the
+/// files that were created or changed.
+pub fn new_day(root: &Path, year: u32, day: u32, title: &str) -> Result<Vec<PathBuf>> {
+    if !(2015..=2100).contains(&year) || !(1..=25).contains(&day) {
+        bail!("there is no puzzle for {} day {}", year, day);
+    }
+    let solutions = root.join("src/solutions");
+    let registry_path = solutions.join("mod.rs");
+    let registry = read(&registry_path)?;
+    let solution_path = solutions.join(format!("y{}/day{}.rs", year, day));
+    if solution_path.exists() {
+        bail!("{} already exists", solution_path.display());
+    }
+    if registry.contains(&format!("y{}::day{}::", year, day)) {
+        bail!("{} day {} is already registered", year, day);
+    }
+
+    // Work out every change before touching any file.
+    let mut writes = vec![(solution_path, solution(year, day, title)?)];
+    let mut registry = insert_sorted(
+        &registry,
+        &format!("    &y{}::day{}::Puzzle,", year, day),
+        (year, day),
+        registry_entry,
+    )
+    .context("could not find the list of solutions")?;
+    let year_path = solutions.join(format!("y{}/mod.rs", year));
+    let day_mod = format!("pub mod day{};", day);
+    if year_path.exists() {
+        let year_mod = read(&year_path)?;
+        let year_mod = insert_sorted(&year_mod, &day_mod, day, |line| {
+            number_between(line, "pub mod day", ";")
+        })
+        .unwrap_or_else(|| format!("{}{}\n", year_mod, day_mod));
+        writes.push((year_path, year_mod));
+    } else {
+        writes.push((year_path, format!("{}\n", day_mod)));
+        registry = insert_sorted(&registry, &format!("pub mod y{};", year), year, |line| {
+            number_between(line, "pub mod y", ";")
+        })
+        .context("could not find the year modules")?;
+    }
+    writes.push((registry_path, registry));
+
+    let data = root.join(format!("data/{}", year));
+    for (extension, stub) in [
+        ("input", ""),
+        ("example", ""),
+        (
+            "example.answers",
+            "# The answers of the example, e.g. \"1: 24000\"\n",
+        ),
+    ] {
+        let path = data.join(format!("{}.{}", day, extension));
+        if !path.exists() {
+            writes.push((path, stub.to_string()));
+        }
+    }
+
+    let mut written = Vec::new();
+    for (path, contents) in writes {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)
+                .with_context(|| format!("could not create {}", dir.display()))?;
+        }
+        std::fs::write(&path, contents)
+            .with_context(|| format!("could not write {}", path.display()))?;
+        written.push(path);
+    }
+    Ok(written)
+}
+
+fn read(path: &Path) -> Result<String> {
+    std::fs::read_to_string(path).with_context(|| format!("could not read {}", path.display()))
+}
+
+/// The template with its year, day and title filled in.
+fn solution(year: u32, day: u32, title: &str) -> Result<String> {
+    let mut text = TEMPLATE.to_string();
+    for (from, to) in [
+        (
+            "pub const DAY: u32 = 0;".to_string(),
+            format!("pub const DAY: u32 = {};", day),
+        ),
+        (
+            "pub const YEAR: u32 = 2023;".to_string(),
+            format!("pub const YEAR: u32 = {};", year),
+        ),
+        (
+            "crate::solution!(\"-\");".to_string(),
+            format!("crate::solution!({:?});", title),
+        ),
+    ] {
+        if !text.contains(&from) {
+            bail!("the template no longer contains {:?}", from);
+        }
+        text = text.replace(&from, &to);
+    }
+    Ok(text)
+}
+
+fn registry_entry(line: &str) -> Option<(u32, u32)> {
+    let (year, day) = line.trim().strip_prefix("&y")?.split_once("::day")?;
+    Some((
+        year.parse().ok()?,
+        day.strip_suffix("::Puzzle,")?.parse().ok()?,
+    ))
+}
+
+fn number_between(line: &str, prefix: &str, suffix: &str) -> Option<u32> {
+    line.strip_prefix(prefix)?
+        .strip_suffix(suffix)?
+        .parse()
+        .ok()
+}
+
+/// Inserts `new_line` among the lines for which `key` gives a key, keeping them sorted.
+/// Returns `None` when there are no such lines.
+fn insert_sorted<K: Ord>(
+    text: &str,
+    new_line: &str,
+    new_key: K,
+    key: impl Fn(&str) -> Option<K>,
+) -> Option<String> {
+    let mut lines: Vec<&str> = text.lines().collect();
+    let entries: Vec<(usize, K)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| Some((i, key(line)?)))
+        .collect();
+    let last = entries.last()?.0;
+    let at = entries
+        .iter()
+        .find(|(_, k)| *k > new_key)
+        .map_or(last + 1, |(i, _)| *i);
+    lines.insert(at, new_line);
+    Some(lines.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn fake_repo(name: &str) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("aoc-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(root.join("src/solutions/y2023")).unwrap();
+        std::fs::write(
+            root.join("src/solutions/mod.rs"),
+            include_str!("solutions/mod.rs"),
+        )
+        .unwrap();
+        std::fs::write(
+            root.join("src/solutions/y2023/mod.rs"),
+            include_str!("solutions/y2023/mod.rs"),
+        )
+        .unwrap();
+        root
+    }
+
+    #[test]
+    fn add_day_to_existing_year() {
+        let root = fake_repo("new-day");
+        let written = new_day(&root, 2023, 4, "Scratchcards").unwrap();
+        assert_eq!(written.len(), 6);
+
+        let day = read(&root.join("src/solutions/y2023/day4.rs")).unwrap();
+        assert!(day.contains("pub const DAY: u32 = 4;"));
+        assert!(day.contains("crate::solution!(\"Scratchcards\");"));
+        let year = read(&root.join("src/solutions/y2023/mod.rs")).unwrap();
+        assert!(year.ends_with("pub mod day3;\npub mod day4;\n"));
+        let registry = read(&root.join("src/solutions/mod.rs")).unwrap();
+        assert!(registry.contains("    &y2023::day3::Puzzle,\n    &y2023::day4::Puzzle,\n];"));
+        assert_eq!(read(&root.join("data/2023/4.input")).unwrap(), "");
+
+        let err = new_day(&root, 2023, 4, "-").unwrap_err();
+        assert!(err.to_string().contains("already exists"));
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn add_new_year_and_keep_data() {
+        let root = fake_repo("new-year");
+        std::fs::create_dir_all(root.join("data/2016")).unwrap();
+        std::fs::write(root.join("data/2016/8.input"), "rect 3x2\n").unwrap();
+        new_day(&root, 2016, 8, "-").unwrap();
+
+        let registry = read(&root.join("src/solutions/mod.rs")).unwrap();
+        assert!(registry.contains("pub mod y2015;\npub mod y2016;\npub mod y2021;"));
+        assert!(registry.contains("    &y2015::day1::Puzzle,\n    &y2016::day8::Puzzle,\n"));
+        let year = read(&root.join("src/solutions/y2016/mod.rs")).unwrap();
+        assert_eq!(year, "pub mod day8;\n");
+        assert_eq!(read(&root.join("data/2016/8.input")).unwrap(), "rect 3x2\n");
+
+        assert!(new_day(&root, 2016, 26, "-").is_err());
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,175 @@
+//! Shortest paths on a [`Grid`], built on the `pathfinding` crate.
+//!
+//! Every search steps between orthogonal neighbours, as far as `can_move(from, to)`
+//! allows, and may start at any of several sources and stop at the first cell for
+//! which `is_target` holds.
+
+use crate::grid::{Grid, Pos};
+use pathfinding::{num_traits::Zero, prelude};
+
+/// A shortest path from one of the sources to a target.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Path<C> {
+    /// Every cell on the path, the source and the target included
+    pub cells: Vec<Pos>,
+    pub cost: C,
+}
+
+impl<C> Path<C> {
+    pub fn start(&self) -> Pos {
+        self.cells[0]
+    }
+
+    pub fn end(&self) -> Pos {
+        self.cells[self.cells.len() - 1]
+    }
+
+    pub fn steps(&self) -> usize {
+        self.cells.len() - 1
+    }
+}
+
+/// The searches run on `Option<Pos>`, with `None` as a node in front of all sources.
+fn successors<'a, T, C: Zero>(
+    grid: &'a Grid<T>,
+    sources: &'a [Pos],
+    can_move: &'a impl Fn(Pos, Pos) -> bool,
+    cost: &'a impl Fn(Pos, Pos) -> C,
+) -> impl Fn(&Option<Pos>) -> Vec<(Option<Pos>, C)> + 'a {
+    move |node| match *node {
+        None => sources.iter().map(|&s| (Some(s), C::zero())).collect(),
+        Some(from) => grid
+            .neighbours4(from)
+            .filter(|&to| can_move(from, to))
+            .map(|to| (Some(to), cost(from, to)))
+            .collect(),
+    }
+}
+
+fn path<C>(nodes: Vec<Option<Pos>>, cost: C) -> Path<C> {
+    Path {
+        cells: nodes.into_iter().flatten().collect(),
+        cost,
+    }
+}
+
+/// The path with the fewest steps, its cost is the number of steps.
+pub fn bfs<T>(
+    grid: &Grid<T>,
+    sources: impl IntoIterator<Item = Pos>,
+    can_move: impl Fn(Pos, Pos) -> bool,
+    is_target: impl Fn(Pos) -> bool,
+) -> Option<Path<usize>> {
+    let sources: Vec<Pos> = sources.into_iter().collect();
+    let successors = successors(grid, &sources, &can_move, &|_, _| 1);
+    let nodes = prelude::bfs(
+        &None,
+        |node| successors(node).into_iter().map(|(n, _)| n),
+        |node| node.is_some_and(&is_target),
+    )?;
+    let steps = nodes.len() - 2;
+    Some(path(nodes, steps))
+}
+
+/// The cheapest path, where a step from `a` to `b` costs `cost(a, b)`.
+pub fn dijkstra<T, C>(
+    grid: &Grid<T>,
+    sources: impl IntoIterator<Item = Pos>,
+    can_move: impl Fn(Pos, Pos) -> bool,
+    cost: impl Fn(Pos, Pos) -> C,
+    is_target: impl Fn(Pos) -> bool,
+) -> Option<Path<C>>
+where
+    C: Zero + Ord + Copy,
+{
+    let sources: Vec<Pos> = sources.into_iter().collect();
+    let (nodes, cost) = prelude::dijkstra(
+        &None,
+        successors(grid, &sources, &can_move, &cost),
+        |node| node.is_some_and(&is_target),
+    )?;
+    Some(path(nodes, cost))
+}
+
+/// Like [`dijkstra`], guided by `heuristic`, which must never overestimate the
+/// cost from a cell to the nearest target.
+pub fn astar<T, C>(
+    grid: &Grid<T>,
+    sources: impl IntoIterator<Item = Pos>,
+    can_move: impl Fn(Pos, Pos) -> bool,
+    cost: impl Fn(Pos, Pos) -> C,
+    heuristic: impl Fn(Pos) -> C,
+    is_target: impl Fn(Pos) -> bool,
+) -> Option<Path<C>>
+where
+    C: Zero + Ord + Copy,
+{
+    let sources: Vec<Pos> = sources.into_iter().collect();
+    let (nodes, cost) = prelude::astar(
+        &None,
+        successors(grid, &sources, &can_move, &cost),
+        |node| node.map_or(C::zero(), &heuristic),
+        |node| node.is_some_and(&is_target),
+    )?;
+    Some(path(nodes, cost))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::geometry::Coord;
+
+    const MAZE: &str = "\
+S.#....
+.##.##.
+...#...
+.#...#E
+";
+
+    fn maze() -> Grid<char> {
+        Grid::parse(MAZE, Some).unwrap()
+    }
+
+    #[test]
+    fn fewest_steps() {
+        let maze = maze();
+        let open = |_, to| maze[to] != '#';
+        let start = maze.position(|&c| c == 'S').unwrap();
+        let path = bfs(&maze, [start], open, |p| maze[p] == 'E').unwrap();
+        assert_eq!(path.cost, 11);
+        assert_eq!((path.start(), path.end()), ((0, 0), (6, 3)));
+        assert_eq!(path.steps(), path.cost);
+        assert!(path
+            .cells
+            .windows(2)
+            .all(|w| Coord::from(w[0]).manhattan(w[1].into()) == 1));
+
+        // Any cell in the top row may be a source, the nearest one wins
+        let top = (0..maze.width()).map(|x| (x, 0));
+        let path = bfs(&maze, top, open, |p| maze[p] == 'E').unwrap();
+        assert_eq!((path.start(), path.cost), ((6, 0), 3));
+
+        assert_eq!(bfs(&maze, [start], |_, _| false, |p| p == (6, 3)), None);
+        assert_eq!(bfs(&maze, [start], open, |p| p == start).unwrap().cost, 0);
+    }
+
+    #[test]
+    fn cheapest_path() {
+        let maze = maze();
+        let open = |_, to: Pos| maze[to] != '#';
+        // Every step down costs 5, the path has to make four of them
+        let cost = |from: Pos, to: Pos| if to.1 > from.1 { 5 } else { 1 };
+        let targets = |p| maze[p] == 'E';
+
+        let path = dijkstra(&maze, [(0, 0)], open, cost, targets).unwrap();
+        assert_eq!(path.cost, 27);
+        let end = Coord::from((6, 3));
+        let heuristic = |p: Pos| Coord::from(p).manhattan(end);
+        let guided = astar(&maze, [(0, 0)], open, cost, heuristic, targets).unwrap();
+        assert_eq!(guided.cost, path.cost);
+        assert_eq!(
+            astar(&maze, [(0, 0)], |_, _| false, cost, heuristic, targets),
+            None
+        );
+    }
+}
@@ -0,0 +1,882 @@
+//! Sliding puzzles, like the 8-puzzle and the 15-puzzle.
+//!
+//! A board is a rectangle of numbered tiles with one blank, written as 0. It is
+//! solved when the tiles are in order, row by row, with the blank last. Moves are
+//! written as `U`, `D`, `L` and `R`: the direction the blank moves in, so `U`
+//! slides the tile above the blank down.
+//!
+//! Boards are packed into a `u64`, four bits per tile, so they are cheap to copy
+//! and hash and a search does not allocate for every board it looks at. That
+//! limits them to [`MAX_TILES`] tiles, enough for the 15-puzzle.
+//!
+//! The searches are guided by a [`Heuristic`]. The stronger ones are Manhattan
+//! distance plus linear conflicts, and additive pattern databases, which are
+//! built once per board shape and can be cached in a file.
+
+use crate::{error::ParseError, input};
+use anyhow::{anyhow, bail, Context, Result};
+use pathfinding::prelude;
+use std::{cell::Cell, collections::VecDeque, fmt, path::Path, str::FromStr};
+
+/// The most tiles a board can have, the blank included.
+pub const MAX_TILES: usize = 16;
+
+/// The moves, in the order [`Node::successors`] tries them.
+pub const MOVES: [char; 4] = ['U', 'D', 'R', 'L'];
+
+/// How to search for the shortest solution.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Algorithm {
+    /// Fast, but keeps every board it has seen in memory
+    AStar,
+    /// Iterative deepening A*, slower but with next to no memory
+    IdaStar,
+}
+
+/// An estimate of the number of moves left, which never overestimates it.
+pub enum Heuristic {
+    /// The Manhattan distance of every tile to its place
+    Manhattan,
+    /// Manhattan distance plus two moves for every tile that has to step out of
+    /// the way of another one in its goal row or column
+    LinearConflict,
+    /// Additive pattern databases
+    Patterns(PatternDatabase),
+}
+
+impl Heuristic {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Heuristic::Manhattan => "manhattan",
+            Heuristic::LinearConflict => "linear-conflict",
+            Heuristic::Patterns(_) => "patterns",
+        }
+    }
+
+    /// The estimate for `node` to reach `goal`. The pattern databases only know
+    /// [`Node::solved`], so for them that has to be the goal.
+    pub fn estimate(&self, node: &Node, goal: &Node) -> u32 {
+        match self {
+            Heuristic::Manhattan => node.man_distance(goal),
+            Heuristic::LinearConflict => node.linear_conflict(goal),
+            Heuristic::Patterns(patterns) => patterns.estimate(node),
+        }
+    }
+}
+
+/// A shortest solution, and how much work it took to find it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Solved {
+    /// The moves of the blank, as `UDLR`
+    pub moves: String,
+    /// The number of boards whose successors were generated
+    pub expanded: usize,
+}
+
+/// A board, with the position of the blank kept at hand.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Node {
+    /// Tile `i`, counted row by row, in bits `4 * i` to `4 * i + 3`
+    tiles: u64,
+    width: u8,
+    height: u8,
+    blank: u8,
+}
+
+impl fmt::Display for Node {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for y in 0..self.height() {
+            for x in 0..self.width() {
+                if x > 0 {
+                    write!(f, " ")?;
+                }
+                write!(f, "{:>2}", self.get(y, x))?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// Reads a board with one row per line and the tiles separated by whitespace.
+/// The blank can be written as `0` or `_`.
+impl FromStr for Node {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let lines: Vec<&str> = text.lines().filter(|l| !l.trim().is_empty()).collect();
+        let width = lines.first().map_or(0, |l| l.split_whitespace().count());
+        let count = width * lines.len();
+        let mut tiles = Vec::with_capacity(count);
+        for &line in &lines {
+            let row = line
+                .split_whitespace()
+                .map(|tile| match tile {
+                    "_" => Ok(0),
+                    tile => input::parse_at(line, tile),
+                })
+                .collect::<Result<Vec<u8>, _>>()
+                .map_err(|e| e.relocate(text))?;
+            if row.len() != width {
+                let message = format!("expected {} tiles like the first row", width);
+                return Err(ParseError::at(text, line, message));
+            }
+            for tile in row {
+                if tile as usize >= count || tiles.contains(&tile) {
+                    let message = format!("{} is not a tile of this board, or twice on it", tile);
+                    return Err(ParseError::at(text, line, message));
+                }
+                tiles.push(tile);
+            }
+        }
+        Node::new(width, lines.len(), &tiles).map_err(|e| ParseError::at(text, text, e.to_string()))
+    }
+}
+
+#[allow(clippy::result_unit_err)]
+impl Node {
+    /// A board of `width` by `height` with `tiles` row by row, which must hold every
+    /// number from 0 up to the number of tiles once.
+    pub fn new(width: usize, height: usize, tiles: &[u8]) -> Result<Node> {
+        check_shape(width, height)?;
+        let count = width * height;
+        if tiles.len() != count {
+            bail!(
+                "a {}x{} board has {} tiles, not {}",
+                width,
+                height,
+                count,
+                tiles.len()
+            );
+        }
+        let mut seen = 0u32;
+        for &tile in tiles {
+            if tile as usize >= count || seen & (1 << tile) != 0 {
+                bail!(
+                    "{} is not a tile of a {}x{} board, or twice on it",
+                    tile,
+                    width,
+                    height
+                );
+            }
+            seen |= 1 << tile;
+        }
+        let packed = tiles
+            .iter()
+            .enumerate()
+            .fold(0, |packed, (i, &t)| packed | (t as u64) << (4 * i));
+        Ok(Node {
+            tiles: packed,
+            width: width as u8,
+            height: height as u8,
+            blank: tiles.iter().position(|&t| t == 0).unwrap() as u8,
+        })
+    }
+
+    /// The solved board of `width` by `height`.
+    pub fn goal(width: usize, height: usize) -> Node {
+        if let Err(e) = check_shape(width, height) {
+            panic!("{}", e);
+        }
+        let count = width * height;
+        // Tile `t` at index `t - 1`, and the blank last
+        let tiles = (1..count).fold(0, |packed, t| packed | (t as u64) << (4 * (t - 1)));
+        Node {
+            tiles,
+            width: width as u8,
+            height: height as u8,
+            blank: (count - 1) as u8,
+        }
+    }
+
+    /// The solved board of the same shape.
+    pub fn solved(&self) -> Node {
+        Node::goal(self.width(), self.height())
+    }
+
+    /// A random board of `width` by `height` that can be solved, the same one for
+    /// the same `seed`.
+    pub fn shuffled(width: usize, height: usize, seed: u64) -> Result<Node> {
+        check_shape(width, height)?;
+        let mut state = seed;
+        // splitmix64, to not need a crate for random numbers
+        let mut random = move || {
+            state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+            let mut z = state;
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+            z ^ (z >> 31)
+        };
+        let count = width * height;
+        let mut tiles: Vec<u8> = (0..count as u8).collect();
+        for i in (1..count).rev() {
+            tiles.swap(i, (random() % (i as u64 + 1)) as usize);
+        }
+        let mut node = Node::new(width, height, &tiles).unwrap();
+        if !node.is_solvable() {
+            // Swapping two tiles flips the parity of the inversions
+            let pair: Vec<usize> = (0..count).filter(|&i| tiles[i] != 0).take(2).collect();
+            tiles.swap(pair[0], pair[1]);
+            node = Node::new(width, height, &tiles).unwrap();
+        }
+        Ok(node)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width as usize
+    }
+
+    pub fn height(&self) -> usize {
+        self.height as usize
+    }
+
+    /// The number of tiles, the blank included.
+    pub fn area(&self) -> usize {
+        self.width() * self.height()
+    }
+
+    /// The tile at index `i`, counted row by row.
+    pub fn tile(&self, i: usize) -> u8 {
+        ((self.tiles >> (4 * i)) & 0xf) as u8
+    }
+
+    /// The tile in row `y` and column `x`.
+    pub fn get(&self, y: usize, x: usize) -> u8 {
+        self.tile(y * self.width() + x)
+    }
+
+    /// The row and column of the blank.
+    pub fn blank(&self) -> (usize, usize) {
+        let blank = self.blank as usize;
+        (blank / self.width(), blank % self.width())
+    }
+
+    /// The board after moving the blank as `m` (one of `UDLR`) says, if it can.
+    pub fn slide(&self, m: char) -> Option<Node> {
+        let (y, x) = self.blank();
+        let to = match m {
+            'U' if y > 0 => self.blank as usize - self.width(),
+            'D' if y + 1 < self.height() => self.blank as usize + self.width(),
+            'L' if x > 0 => self.blank as usize - 1,
+            'R' if x + 1 < self.width() => self.blank as usize + 1,
+            _ => return None,
+        };
+        let tile = self.tile(to) as u64;
+        Some(Node {
+            tiles: (self.tiles & !(0xf << (4 * to))) | tile << (4 * self.blank),
+            blank: to as u8,
+            ..*self
+        })
+    }
+
+    pub fn move_left(&mut self) -> Result<(), ()> {
+        self.apply_move('L')
+    }
+
+    pub fn move_right(&mut self) -> Result<(), ()> {
+        self.apply_move('R')
+    }
+
+    pub fn move_up(&mut self) -> Result<(), ()> {
+        self.apply_move('U')
+    }
+
+    pub fn move_down(&mut self) -> Result<(), ()> {
+        self.apply_move('D')
+    }
+
+    /// Moves the blank as `m` (one of `UDLR`) says.
+    pub fn apply_move(&mut self, m: char) -> Result<(), ()> {
+        *self = self.slide(m).ok_or(())?;
+        Ok(())
+    }
+
+    /// Replays `moves` and returns the board they lead to.
+    pub fn replay(&self, moves: &str) -> Result<Node> {
+        let mut node = *self;
+        for (i, m) in moves.chars().enumerate() {
+            if node.apply_move(m).is_err() {
+                bail!("move {} ({:?}) is not possible on\n{}", i + 1, m, node);
+            }
+        }
+        Ok(node)
+    }
+
+    /// Whether `moves` solve this board.
+    pub fn verify(&self, moves: &str) -> bool {
+        self.replay(moves).is_ok_and(|node| node == self.solved())
+    }
+
+    /// The (max 4) boards one move away, each at a cost of 1.
+    pub fn successors(&self) -> impl Iterator<Item = (Node, u32)> {
+        let node = *self;
+        MOVES
+            .into_iter()
+            .filter_map(move |m| node.slide(m))
+            .map(|n| (n, 1))
+    }
+
+    /// The row and column of `value`.
+    pub fn find(&self, value: u8) -> (usize, usize) {
+        match (0..self.area()).find(|&i| self.tile(i) == value) {
+            Some(i) => (i / self.width(), i % self.width()),
+            None => panic!("{} is not on the board", value),
+        }
+    }
+
+    /// The index of every tile on this board.
+    fn indices(&self) -> [usize; MAX_TILES] {
+        let mut indices = [0; MAX_TILES];
+        for i in 0..self.area() {
+            indices[self.tile(i) as usize] = i;
+        }
+        indices
+    }
+
+    pub fn man_distance(&self, goal: &Node) -> u32 {
+        // manhattan distance, so the sum of the distance from each number in this to its
+        // position in goal. The blank is left out, or the distance could be more than
+        // the number of moves left.
+        let goal_index = goal.indices();
+        let width = self.width();
+        (0..self.area())
+            .filter(|&i| self.tile(i) != 0)
+            .map(|i| {
+                let g = goal_index[self.tile(i) as usize];
+                ((i / width).abs_diff(g / width) + (i % width).abs_diff(g % width)) as u32
+            })
+            .sum()
+    }
+
+    /// The Manhattan distance plus linear conflicts.
+    ///
+    /// Tiles that are in their goal row, but in the wrong order, cannot all pass
+    /// each other in that row: all but the longest run in the right order have to
+    /// step out and back in, which costs two moves each. The same goes for columns,
+    /// and as those are vertical and horizontal moves the two never overlap.
+    pub fn linear_conflict(&self, goal: &Node) -> u32 {
+        let goal_index = goal.indices();
+        let (width, height) = (self.width(), self.height());
+        let mut conflicts = 0;
+        // The goal columns of the tiles in a row that belong in that row, in the
+        // order they are in now, and likewise the goal rows for a column
+        let mut line = [0; MAX_TILES];
+        for y in 0..height {
+            let mut len = 0;
+            for i in (0..width).map(|x| y * width + x) {
+                let g = goal_index[self.tile(i) as usize];
+                if self.tile(i) != 0 && g / width == y {
+                    line[len] = g % width;
+                    len += 1;
+                }
+            }
+            conflicts += len - longest_increasing(&line[..len]);
+        }
+        for x in 0..width {
+            let mut len = 0;
+            for i in (0..height).map(|y| y * width + x) {
+                let g = goal_index[self.tile(i) as usize];
+                if self.tile(i) != 0 && g % width == x {
+                    line[len] = g / width;
+                    len += 1;
+                }
+            }
+            conflicts += len - longest_increasing(&line[..len]);
+        }
+        self.man_distance(goal) + 2 * conflicts as u32
+    }
+
+    /// Whether the goal can be reached at all.
+    ///
+    /// Every move keeps the parity of the number of inversions (pairs of tiles in
+    /// the wrong order, reading row by row) when the board is odd in width. On an
+    /// even width a move up or down flips it, so the row of the blank counts too.
+    pub fn is_solvable(&self) -> bool {
+        let tiles = (0..self.area()).map(|i| self.tile(i)).filter(|&t| t != 0);
+        let inversions: usize = tiles
+            .clone()
+            .enumerate()
+            .map(|(i, t)| tiles.clone().skip(i + 1).filter(|&u| u < t).count())
+            .sum();
+        if self.width() % 2 == 1 {
+            inversions.is_multiple_of(2)
+        } else {
+            let blank_row_from_bottom = self.height() - self.blank().0;
+            (inversions + blank_row_from_bottom) % 2 == 1
+        }
+    }
+
+    /// The shortest sequence of moves that solves the board.
+    pub fn solve(&self, algorithm: Algorithm) -> Result<String> {
+        Ok(self
+            .solve_with(algorithm, &Heuristic::LinearConflict)?
+            .moves)
+    }
+
+    /// Like [`Node::solve`], guided by `heuristic`.
+    pub fn solve_with(&self, algorithm: Algorithm, heuristic: &Heuristic) -> Result<Solved> {
+        if !self.is_solvable() {
+            bail!("this board cannot be solved");
+        }
+        if let Heuristic::Patterns(patterns) = heuristic {
+            if (patterns.width, patterns.height) != (self.width(), self.height()) {
+                bail!(
+                    "the pattern databases are for {}x{} boards, not {}x{}",
+                    patterns.width,
+                    patterns.height,
+                    self.width(),
+                    self.height()
+                );
+            }
+        }
+        let goal = self.solved();
+        let expanded = Cell::new(0);
+        let successors = |node: &Node| {
+            expanded.set(expanded.get() + 1);
+            node.successors()
+        };
+        let estimate = |node: &Node| heuristic.estimate(node, &goal);
+        let success = |node: &Node| *node == goal;
+        let (path, _) = match algorithm {
+            Algorithm::AStar => prelude::astar(self, successors, estimate, success),
+            Algorithm::IdaStar => prelude::idastar(self, successors, estimate, success),
+        }
+        .ok_or_else(|| anyhow!("no solution found"))?;
+        let moves = path
+            .windows(2)
+            .map(|pair| {
+                MOVES
+                    .into_iter()
+                    .find(|&m| pair[0].slide(m) == Some(pair[1]))
+                    .unwrap()
+            })
+            .collect();
+        Ok(Solved {
+            moves,
+            expanded: expanded.get(),
+        })
+    }
+}
+
+/// Boards need at least two rows and two columns, as in a single row or column the
+/// tiles can never pass each other, and at most [`MAX_TILES`] tiles.
+fn check_shape(width: usize, height: usize) -> Result<()> {
+    if width < 2 || height < 2 {
+        bail!(
+            "a board needs at least 2 rows and 2 columns, not {}x{}",
+            width,
+            height
+        );
+    }
+    if width * height > MAX_TILES {
+        bail!(
+            "a board has at most {} tiles, not {}",
+            MAX_TILES,
+            width * height
+        );
+    }
+    Ok(())
+}
+
+/// The length of the longest strictly increasing subsequence of `values`.
+fn longest_increasing(values: &[usize]) -> usize {
+    let mut longest = [0; MAX_TILES];
+    for i in 0..values.len() {
+        longest[i] = 1
+            + (0..i)
+                .filter(|&j| values[j] < values[i])
+                .map(|j| longest[j])
+                .max()
+                .unwrap_or(0);
+    }
+    longest[..values.len()].iter().copied().max().unwrap_or(0)
+}
+
+/// Additive pattern databases for boards of one shape.
+///
+/// The tiles are split into groups. For every group, a table holds the fewest
+/// moves of that group's tiles needed to bring them home from any placement,
+/// found by a breadth first search back from the goal in which moving any other
+/// tile is free. As each move counts for one group only, the sum over the groups
+/// never overestimates, and it is far better informed than Manhattan distance.
+pub struct PatternDatabase {
+    width: usize,
+    height: usize,
+    groups: Vec<Vec<u8>>,
+    /// Per group, indexed by the positions of its tiles (see `encode`)
+    tables: Vec<Vec<u8>>,
+}
+
+impl PatternDatabase {
+    /// The most tiles in a group, which keeps a 15-puzzle table at 1 MB.
+    pub const GROUP_SIZE: usize = 5;
+
+    /// The tiles in order, split into as few groups as fit, all of about the same size.
+    pub fn default_groups(width: usize, height: usize) -> Vec<Vec<u8>> {
+        let tiles = width * height - 1;
+        let count = tiles.div_ceil(Self::GROUP_SIZE);
+        (0..count)
+            .map(|g| {
+                (g * tiles / count + 1..=(g + 1) * tiles / count)
+                    .map(|t| t as u8)
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Builds the tables for `groups`, which must not share tiles.
+    pub fn build(width: usize, height: usize, groups: Vec<Vec<u8>>) -> Result<PatternDatabase> {
+        let goal = Node::goal(width, height);
+        let mut seen = 0u32;
+        for &tile in groups.iter().flatten() {
+            if tile == 0 || tile as usize >= goal.area() || seen & (1 << tile) != 0 {
+                bail!("{} cannot be in a group, or is in two of them", tile);
+            }
+            seen |= 1 << tile;
+        }
+        if groups
+            .iter()
+            .any(|g| g.is_empty() || g.len() > Self::GROUP_SIZE)
+        {
+            bail!("groups need 1 to {} tiles", Self::GROUP_SIZE);
+        }
+        let tables = groups
+            .iter()
+            .map(|group| build_table(width, height, group))
+            .collect();
+        Ok(PatternDatabase {
+            width,
+            height,
+            groups,
+            tables,
+        })
+    }
+
+    /// Reads the databases for the default groups from `path`, or builds and
+    /// saves them there when the file is missing or for other groups.
+    pub fn load_or_build(path: &Path, width: usize, height: usize) -> Result<PatternDatabase> {
+        let groups = Self::default_groups(width, height);
+        if let Ok(bytes) = std::fs::read(path) {
+            if let Some(patterns) = Self::from_bytes(width, height, &groups, &bytes) {
+                return Ok(patterns);
+            }
+        }
+        let patterns = Self::build(width, height, groups)?;
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)
+                .with_context(|| format!("could not create {}", dir.display()))?;
+        }
+        std::fs::write(path, patterns.to_bytes())
+            .with_context(|| format!("could not write {}", path.display()))?;
+        Ok(patterns)
+    }
+
+    /// A line that describes the shape and groups, so a stale file is noticed.
+    fn header(width: usize, height: usize, groups: &[Vec<u8>]) -> String {
+        let groups: Vec<String> = groups
+            .iter()
+            .map(|g| {
+                g.iter()
+                    .map(|t| t.to_string())
+                    .collect::<Vec<_>>()
+                    .join(",")
+            })
+            .collect();
+        format!(
+            "pattern databases {}x{} {}\n",
+            width,
+            height,
+            groups.join(" ")
+        )
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Self::header(self.width, self.height, &self.groups).into_bytes();
+        self.tables.iter().for_each(|t| bytes.extend_from_slice(t));
+        bytes
+    }
+
+    fn from_bytes(
+        width: usize,
+        height: usize,
+        groups: &[Vec<u8>],
+        bytes: &[u8],
+    ) -> Option<PatternDatabase> {
+        let header = Self::header(width, height, groups);
+        let mut rest = bytes.strip_prefix(header.as_bytes())?;
+        let mut tables = Vec::new();
+        for group in groups {
+            let (table, tail) = rest.split_at_checked((width * height).pow(group.len() as u32))?;
+            tables.push(table.to_vec());
+            rest = tail;
+        }
+        rest.is_empty().then(|| PatternDatabase {
+            width,
+            height,
+            groups: groups.to_vec(),
+            tables,
+        })
+    }
+
+    /// The sum of the table entries for the positions of each group's tiles.
+    pub fn estimate(&self, node: &Node) -> u32 {
+        let indices = node.indices();
+        let area = node.area();
+        self.groups
+            .iter()
+            .zip(&self.tables)
+            .map(|(group, table)| {
+                let positions = group.iter().map(|&t| indices[t as usize]);
+                table[encode(area, positions)] as u32
+            })
+            .sum()
+    }
+}
+
+/// A placement of tiles as one number, the position of the first tile lowest.
+fn encode(area: usize, positions: impl DoubleEndedIterator<Item = usize>) -> usize {
+    positions.rev().fold(0, |index, p| index * area + p)
+}
+
+/// The table for one group: a 0-1 breadth first search from the goal over the
+/// positions of its tiles and the blank, in which only moving a tile of the
+/// group costs a move. The blank is then left out by taking the best of all its
+/// positions.
+fn build_table(width: usize, height: usize, group: &[u8]) -> Vec<u8> {
+    let area = width * height;
+    let size = area.pow(group.len() as u32);
+    // A state is the placement of the group times `area`, plus the blank
+    let mut moves = vec![u8::MAX; size * area];
+    let goal = group.iter().map(|&t| t as usize - 1);
+    let start = encode(area, goal) * area + area - 1;
+    moves[start] = 0;
+    let mut queue = VecDeque::from([start]);
+    let mut positions = [0; MAX_TILES];
+    let positions = &mut positions[..group.len()];
+    while let Some(state) = queue.pop_front() {
+        let blank = state % area;
+        let mut placement = state / area;
+        for p in positions.iter_mut() {
+            *p = placement % area;
+            placement /= area;
+        }
+        let (y, x) = (blank / width, blank % width);
+        let neighbours = [
+            (y > 0).then(|| blank - width),
+            (y + 1 < height).then(|| blank + width),
+            (x > 0).then(|| blank - 1),
+            (x + 1 < width).then(|| blank + 1),
+        ];
+        for to in neighbours.into_iter().flatten() {
+            let (next, cost) = match positions.iter().position(|&p| p == to) {
+                Some(i) => {
+                    positions[i] = blank;
+                    let next = encode(area, positions.iter().copied()) * area + to;
+                    positions[i] = to;
+                    (next, 1)
+                }
+                None => (state - blank + to, 0),
+            };
+            let total = moves[state] + cost;
+            if total < moves[next] {
+                moves[next] = total;
+                if cost == 0 {
+                    queue.push_front(next);
+                } else {
+                    queue.push_back(next);
+                }
+            }
+        }
+    }
+    moves
+        .chunks(area)
+        .map(|blanks| blanks.iter().copied().min().unwrap())
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn board(text: &str) -> Node {
+        text.parse().unwrap()
+    }
+
+    #[test]
+    fn parse_boards() {
+        let node = board("1 2 3\n4 _ 6\n7 5 8\n");
+        assert_eq!((node.find(0), node.blank()), ((1, 1), (1, 1)));
+        assert_eq!(node.get(2, 1), 5);
+        assert_eq!(node.to_string(), " 1  2  3\n 4  0  6\n 7  5  8\n");
+        assert_eq!(board(&Node::goal(4, 4).to_string()), Node::goal(4, 4));
+        let wide = board("1 2 3\n4 5 0");
+        assert_eq!((wide.width(), wide.height()), (3, 2));
+        assert_eq!(wide, Node::goal(3, 2));
+
+        let err = "1 2 3\n4 0\n7 5 8\n".parse::<Node>().unwrap_err();
+        assert_eq!((err.line, err.text.as_str()), (2, "4 0"));
+        let err = "1 2 3\n4 0 6\n7 3 8\n".parse::<Node>().unwrap_err();
+        assert_eq!(err.line, 3);
+        let err = "1 2\n3 x\n".parse::<Node>().unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+        assert!("1 2 3 4 5\n6 7 8 9 10\n11 12 13 14 15\n16 17 18 19 0"
+            .parse::<Node>()
+            .is_err());
+        // Tiles in a single row or column cannot pass each other
+        assert!("3 1 2 0".parse::<Node>().is_err());
+        assert!("1\n0".parse::<Node>().is_err());
+        assert!(Node::new(4, 1, &[3, 1, 2, 0]).is_err());
+    }
+
+    #[test]
+    fn slide_tiles() {
+        let node = board("1 2 3\n4 0 6\n7 5 8");
+        let up = node.slide('U').unwrap();
+        assert_eq!(up, board("1 0 3\n4 2 6\n7 5 8"));
+        assert_eq!(up.blank(), (0, 1));
+        assert_eq!(up.slide('U'), None);
+        assert_eq!(node.successors().count(), 4);
+        assert_eq!(Node::goal(3, 3).successors().count(), 2);
+        assert_eq!(node.man_distance(&Node::goal(3, 3)), 2);
+    }
+
+    #[test]
+    fn solvability() {
+        assert!(Node::goal(3, 3).is_solvable());
+        assert!(Node::goal(4, 4).is_solvable());
+        assert!(!board("2 1 3\n4 5 6\n7 8 0").is_solvable());
+        assert!(!board("1 2 3 4\n5 6 7 8\n9 10 11 12\n13 15 14 0").is_solvable());
+        // One move up from the goal on an even board
+        assert!(board("1 2 3 4\n5 6 7 8\n9 10 11 0\n13 14 15 12").is_solvable());
+        assert!(!board("2 1 3\n4 5 0").is_solvable());
+        assert!(!board("2 1\n3 4\n5 0").is_solvable());
+        assert!(board("2 1 3\n4 5 6\n7 8 0")
+            .solve(Algorithm::AStar)
+            .is_err());
+    }
+
+    #[test]
+    fn solve_and_replay() {
+        let node = board("8 6 7\n2 5 4\n3 0 1");
+        let moves = node.solve(Algorithm::AStar).unwrap();
+        // One of the two hardest 8-puzzle boards
+        assert_eq!(moves.len(), 31);
+        assert!(node.verify(&moves));
+        assert!(!node.verify(&moves[1..]));
+
+        let node = board("1 2 3\n4 0 6\n7 5 8");
+        assert_eq!(node.solve(Algorithm::IdaStar).unwrap(), "DR");
+        assert_eq!(node.solve(Algorithm::AStar).unwrap(), "DR");
+        assert!(node.replay("DD").is_err());
+        assert!(node.replay("X").is_err());
+        assert_eq!(Node::goal(3, 3).solve(Algorithm::AStar).unwrap(), "");
+    }
+
+    #[test]
+    fn solve_rectangles_and_15_puzzles() {
+        let node = board("4 1 3\n0 2 5");
+        let moves = node.solve(Algorithm::AStar).unwrap();
+        assert!(node.verify(&moves));
+        assert_eq!(node.solve(Algorithm::IdaStar).unwrap().len(), moves.len());
+
+        let node = board("10 1 5 6\n8 4 7 11\n0 13 9 15\n14 3 12 2");
+        let moves = node.solve(Algorithm::IdaStar).unwrap();
+        assert!(node.verify(&moves));
+        assert_eq!(moves.len(), 44);
+    }
+
+    #[test]
+    fn shuffle_boards() {
+        let node = Node::shuffled(4, 4, 2022).unwrap();
+        assert_eq!(node, Node::shuffled(4, 4, 2022).unwrap());
+        assert_ne!(node, Node::shuffled(4, 4, 2023).unwrap());
+        for seed in 0..50 {
+            for (width, height) in [(3, 3), (4, 4), (3, 2), (2, 2)] {
+                assert!(Node::shuffled(width, height, seed).unwrap().is_solvable());
+            }
+        }
+        assert!(Node::shuffled(1, 4, 2022).is_err());
+        assert!(Node::shuffled(5, 5, 2022).is_err());
+    }
+
+    #[test]
+    fn linear_conflicts() {
+        let goal = Node::goal(3, 3);
+        // 3 and 1 both have to pass 2, and each other, in the top row
+        let node = board("3 2 1\n4 5 6\n7 8 0");
+        assert_eq!(
+            (node.man_distance(&goal), node.linear_conflict(&goal)),
+            (4, 8)
+        );
+        // 4 and 7 swapped in the first column, the blank does not count
+        let node = board("1 2 3\n7 5 6\n4 8 0");
+        assert_eq!(node.linear_conflict(&goal), node.man_distance(&goal) + 2);
+        assert_eq!(goal.linear_conflict(&goal), 0);
+        assert_eq!(longest_increasing(&[2, 0, 3, 1, 4]), 3);
+    }
+
+    #[test]
+    fn pattern_databases() {
+        assert_eq!(
+            PatternDatabase::default_groups(4, 4),
+            [
+                vec![1, 2, 3, 4, 5],
+                vec![6, 7, 8, 9, 10],
+                vec![11, 12, 13, 14, 15]
+            ]
+        );
+        assert_eq!(
+            PatternDatabase::default_groups(3, 3),
+            [vec![1, 2, 3, 4], vec![5, 6, 7, 8]]
+        );
+        assert!(PatternDatabase::build(3, 3, vec![vec![1, 2], vec![2, 3]]).is_err());
+
+        let path = std::env::temp_dir()
+            .join(format!("aoc-patterns-{}", std::process::id()))
+            .join("3x3.pdb");
+        let patterns = PatternDatabase::load_or_build(&path, 3, 3).unwrap();
+        let cached = PatternDatabase::load_or_build(&path, 3, 3).unwrap();
+        assert_eq!(cached.tables, patterns.tables);
+        std::fs::write(&path, "pattern databases 3x3 1,2,3,4 5,6,7,8\n").unwrap();
+        let rebuilt = PatternDatabase::load_or_build(&path, 3, 3).unwrap();
+        assert_eq!(rebuilt.tables, patterns.tables);
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+
+        let goal = Node::goal(3, 3);
+        let node = board("8 6 7\n2 5 4\n3 0 1");
+        let estimate = patterns.estimate(&node);
+        assert!(estimate >= node.man_distance(&goal));
+        assert!(estimate <= 31);
+        assert_eq!(patterns.estimate(&goal), 0);
+        // With one group holding every tile the table is exact
+        let exact = PatternDatabase::build(3, 2, PatternDatabase::default_groups(3, 2)).unwrap();
+        let node = board("4 1 3\n0 2 5");
+        let moves = node.solve(Algorithm::AStar).unwrap();
+        assert_eq!(exact.estimate(&node), moves.len() as u32);
+    }
+
+    #[test]
+    fn stronger_heuristics_expand_fewer_boards() {
+        let node = board("8 6 7\n2 5 4\n3 0 1");
+        let patterns = PatternDatabase::build(3, 3, PatternDatabase::default_groups(3, 3)).unwrap();
+        let expanded: Vec<usize> = [
+            Heuristic::Manhattan,
+            Heuristic::LinearConflict,
+            Heuristic::Patterns(patterns),
+        ]
+        .iter()
+        .map(|heuristic| {
+            let solved = node.solve_with(Algorithm::IdaStar, heuristic).unwrap();
+            assert_eq!(solved.moves.len(), 31, "{}", heuristic.name());
+            assert!(node.verify(&solved.moves));
+            solved.expanded
+        })
+        .collect();
+        assert!(expanded.windows(2).all(|w| w[0] > w[1]), "{:?}", expanded);
+
+        let patterns = PatternDatabase::build(3, 2, PatternDatabase::default_groups(3, 2)).unwrap();
+        assert!(node
+            .solve_with(Algorithm::AStar, &Heuristic::Patterns(patterns))
+            .is_err());
+    }
+}
@@ -0,0 +1,131 @@
+use crate::{answer::Answer, error};
+use anyhow::Result;
+use std::fmt;
+
+/// One of the two parts every puzzle consists of.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(&self) -> u32 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+
+    pub fn from_number(number: u32) -> Option<Part> {
+        match number {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.number(), f)
+    }
+}
+
+/// A solved (or partially solved) puzzle, identified by its year and day.
+pub trait Solution: Sync {
+    fn year(&self) -> u32;
+
+    fn day(&self) -> u32;
+
+    fn title(&self) -> &'static str;
+
+    fn solve_1(&self, input: &str) -> Result<Answer>;
+
+    fn solve_2(&self, input: &str) -> Result<Answer>;
+
+    /// Only parses `input`, for days where parsing is a separate step.
+    fn parse(&self, _input: &str) -> Option<Result<()>> {
+        None
+    }
+
+    /// Draws how `part` was solved, for days that can.
+    fn visualize(&self, _part: Part, _input: &str) -> Option<Result<String>> {
+        None
+    }
+
+    /// Runs one part, pointing any parse error at its line in `input`.
+    fn solve(&self, part: Part, input: &str) -> Result<Answer> {
+        match part {
+            Part::One => self.solve_1(input),
+            Part::Two => self.solve_2(input),
+        }
+        .map_err(|e| error::locate(e, self.year(), self.day(), input))
+    }
+}
+
+/// Implements [`Solution`] for a day module.
+///
+/// Expects `YEAR`, `DAY`, `solve_1` and `solve_2` to be in scope and
+/// defines a `Puzzle` unit struct that can be put in the registry, plus a test
+/// that runs all examples of the day (see [`crate::examples`]). Days that
+/// parse their input in a separate function can name it with `parse = ...`,
+/// so its time can be measured on its own. Days that can draw what they found
+/// name a `fn(Part, &str) -> Result<String>` with `visualize = ...`.
+#[macro_export]
+macro_rules! solution {
+    ($title:expr $(, $key:ident = $value:path)* $(,)?) => {
+        $crate::solution!(@define $title; $($crate::solution!(@method $key $value);)*);
+    };
+    (@method parse $parse:path) => {
+        fn parse(&self, input: &str) -> Option<anyhow::Result<()>> {
+            Some($parse(input).map(|_| ()))
+        }
+    };
+    (@method visualize $visualize:path) => {
+        fn visualize(
+            &self,
+            part: $crate::solution::Part,
+            input: &str,
+        ) -> Option<anyhow::Result<String>> {
+            Some($visualize(part, input))
+        }
+    };
+    (@define $title:expr; $($methods:tt)*) => {
+        pub struct Puzzle;
+
+        impl $crate::solution::Solution for Puzzle {
+            fn year(&self) -> u32 {
+                YEAR
+            }
+
+            fn day(&self) -> u32 {
+                DAY
+            }
+
+            fn title(&self) -> &'static str {
+                $title
+            }
+
+            fn solve_1(&self, input: &str) -> anyhow::Result<$crate::answer::Answer> {
+                solve_1(input)
+            }
+
+            fn solve_2(&self, input: &str) -> anyhow::Result<$crate::answer::Answer> {
+                solve_2(input)
+            }
+
+            $($methods)*
+        }
+
+        #[cfg(test)]
+        mod examples {
+            #[test]
+            fn expected_answers() {
+                $crate::examples::check(&super::Puzzle).unwrap();
+            }
+        }
+    };
+}
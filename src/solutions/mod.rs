@@ -0,0 +1,91 @@
+use crate::solution::Solution;
+
+pub mod template;
+pub mod y2015;
+pub mod y2021;
+pub mod y2022;
+pub mod y2023;
+
+/// Every implemented puzzle, ordered by year and day.
+static SOLUTIONS: &[&dyn Solution] = &[
+    &y2015::day1::Puzzle,
+    &y2021::day5::Puzzle,
+    &y2022::day1::Puzzle,
+    &y2022::day2::Puzzle,
+    &y2022::day3::Puzzle,
+    &y2022::day4::Puzzle,
+    &y2022::day5::Puzzle,
+    &y2022::day6::Puzzle,
+    &y2022::day7::Puzzle,
+    &y2022::day8::Puzzle,
+    &y2022::day9::Puzzle,
+    &y2022::day10::Puzzle,
+    &y2022::day11::Puzzle,
+    &y2022::day12::Puzzle,
+    &y2023::day1::Puzzle,
+    &y2023::day2::Puzzle,
+    &y2023::day3::Puzzle,
+];
+
+pub fn all() -> impl Iterator<Item = &'static dyn Solution> {
+    SOLUTIONS.iter().copied()
+}
+
+pub fn find(year: u32, day: u32) -> Option<&'static dyn Solution> {
+    all().find(|s| s.year() == year && s.day() == day)
+}
+
+pub fn for_year(year: u32) -> impl Iterator<Item = &'static dyn Solution> {
+    all().filter(move |s| s.year() == year)
+}
+
+pub fn years() -> Vec<u32> {
+    let mut years: Vec<u32> = all().map(|s| s.year()).collect();
+    years.dedup();
+    years
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn registry_is_sorted_and_unique() {
+        let keys: Vec<(u32, u32)> = all().map(|s| (s.year(), s.day())).collect();
+        assert!(keys.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn find_day() {
+        let solution = find(2023, 1).unwrap();
+        assert_eq!(solution.title(), "Trebuchet?!");
+        assert!(find(2023, 26).is_none());
+    }
+
+    #[test]
+    fn years_are_listed_once() {
+        assert_eq!(years(), vec![2015, 2021, 2022, 2023]);
+        assert_eq!(for_year(2022).count(), 12);
+        assert_eq!(for_year(2023).count(), 3);
+    }
+
+    #[test]
+    fn real_inputs_match_stored_answers() {
+        for solution in all() {
+            let path = crate::answers::answers_path(solution.year(), solution.day());
+            if !std::path::Path::new(&path).exists() {
+                continue;
+            }
+            for verified in crate::answers::verify(solution).unwrap() {
+                assert_eq!(
+                    verified.check,
+                    crate::answers::Check::Correct,
+                    "{} day {}, part {}",
+                    solution.year(),
+                    solution.day(),
+                    verified.part
+                );
+            }
+        }
+    }
+}
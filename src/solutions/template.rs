@@ -0,0 +1,19 @@
+use crate::answer::Answer;
+use anyhow::Result;
+
+pub const DAY: u32 = 0;
+pub const YEAR: u32 = 2023;
+
+crate::solution!("-");
+
+fn solve(_input: &str) -> Result<Answer> {
+    Ok(Answer::Unsolved)
+}
+
+pub fn solve_1(input: &str) -> Result<Answer> {
+    solve(input)
+}
+
+pub fn solve_2(input: &str) -> Result<Answer> {
+    solve(input)
+}
@@ -0,0 +1,42 @@
+use crate::answer::Answer;
+use anyhow::{bail, Result};
+
+pub const DAY: u32 = 1;
+pub const YEAR: u32 = 2015;
+
+crate::solution!("Not Quite Lisp");
+
+fn solve(input: &str) -> Result<Answer> {
+    let res: i32 = input
+        .chars()
+        .map(|c| match c {
+            '(' => 1,
+            ')' => -1,
+            _ => 0,
+        })
+        .sum();
+    Ok(res.into())
+}
+
+pub fn solve_1(input: &str) -> Result<Answer> {
+    solve(input)
+}
+
+pub fn solve_2(input: &str) -> Result<Answer> {
+    let res: Vec<i32> = input
+        .chars()
+        .map(|c| match c {
+            '(' => 1,
+            ')' => -1,
+            _ => 0,
+        })
+        .collect();
+    let mut floor: i32 = 0;
+    for (i, v) in res.iter().enumerate() {
+        floor += v;
+        if floor < 0 {
+            return Ok((i + 1).into());
+        }
+    }
+    bail!("Santa never enters the basement")
+}
@@ -0,0 +1,46 @@
+use crate::{
+    answer::Answer,
+    error::ParseError,
+    geometry::{Coord, Segment},
+    input,
+};
+use anyhow::Result;
+use std::collections::HashMap;
+
+pub const DAY: u32 = 5;
+pub const YEAR: u32 = 2021;
+
+crate::solution!("Hydrothermal Venture");
+
+fn parse_vents(input: &str) -> Result<Vec<Segment>> {
+    input::lines_with(input, |line| {
+        let (a, b) = line
+            .split_once(" -> ")
+            .ok_or_else(|| ParseError::at(line, line, "expected \"<point> -> <point>\""))?;
+        let a: Coord = a.parse()?;
+        let b: Coord = b.parse()?;
+        Ok(Segment::new(a, b))
+    })
+}
+
+/// The number of points where at least two of the vents that pass `keep` overlap.
+fn overlaps(input: &str, keep: fn(&Segment) -> bool) -> Result<Answer> {
+    let mut vents = HashMap::new();
+    for segment in parse_vents(input)?.iter().filter(|s| keep(s)) {
+        for point in segment.points() {
+            *vents.entry(point).or_insert(0) += 1;
+        }
+    }
+    let result = vents.values().filter(|&&v| v > 1).count();
+    Ok(result.into())
+}
+
+pub fn solve_1(input: &str) -> Result<Answer> {
+    overlaps(input, |s| s.is_horizontal() || s.is_vertical())
+}
+
+pub fn solve_2(input: &str) -> Result<Answer> {
+    overlaps(input, |s| {
+        s.is_horizontal() || s.is_vertical() || s.is_diagonal()
+    })
+}
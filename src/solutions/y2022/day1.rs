@@ -0,0 +1,24 @@
+use crate::{answer::Answer, input};
+use anyhow::Result;
+
+pub const DAY: u32 = 1;
+pub const YEAR: u32 = 2022;
+
+crate::solution!("Calorie Counting");
+
+fn solve(input: &str, take: usize) -> Result<u32> {
+    let mut res: Vec<u32> = input::groups_of::<u32>(input)?
+        .iter()
+        .map(|elf| elf.iter().sum())
+        .collect();
+    res.sort_by(|a, b| b.cmp(a));
+    Ok(res.iter().take(take).sum::<u32>())
+}
+
+pub fn solve_1(input: &str) -> Result<Answer> {
+    Ok(solve(input, 1)?.into())
+}
+
+pub fn solve_2(input: &str) -> Result<Answer> {
+    Ok(solve(input, 3)?.into())
+}
@@ -0,0 +1,90 @@
+use crate::{answer::Answer, error::ParseError, input, ocr};
+use anyhow::Result;
+
+pub const DAY: u32 = 10;
+pub const YEAR: u32 = 2022;
+
+crate::solution!("Cathode-Ray Tube");
+
+enum Instruction {
+    Noop,
+    Addx(i32),
+}
+
+fn parse_instruction(line: &str) -> Result<Instruction> {
+    match line.split_once(' ') {
+        None if line == "noop" => Ok(Instruction::Noop),
+        Some(("addx", v)) => Ok(Instruction::Addx(input::parse_at(line, v)?)),
+        _ => Err(ParseError::at(line, line, "expected \"noop\" or \"addx <value>\"").into()),
+    }
+}
+
+/// The value of the X register during every cycle.
+fn cycles(input: &str) -> Result<Vec<i32>> {
+    let mut cycles = vec![1];
+    for instruction in input::lines_with(input, parse_instruction)? {
+        let current_x = *cycles.last().unwrap();
+        match instruction {
+            Instruction::Addx(v) => {
+                cycles.push(current_x);
+                cycles.push(current_x + v);
+            }
+            Instruction::Noop => cycles.push(current_x),
+        }
+    }
+    Ok(cycles)
+}
+
+pub fn solve_1(input: &str) -> Result<Answer> {
+    let cycles = cycles(input)?;
+    let result: i32 = [
+        20 * cycles[19],
+        60 * cycles[59],
+        100 * cycles[99],
+        140 * cycles[139],
+        180 * cycles[179],
+        220 * cycles[219],
+    ]
+    .iter()
+    .sum();
+    Ok(result.into())
+}
+
+pub fn solve_2(input: &str) -> Result<Answer> {
+    let cycles = cycles(input)?;
+    let crt: Vec<String> = cycles
+        .chunks(40)
+        .take(6)
+        .map(|row| {
+            row.iter()
+                .enumerate()
+                .map(|(pos, x)| {
+                    if (pos as i32 - x).abs() <= 1 {
+                        '#'
+                    } else {
+                        '.'
+                    }
+                })
+                .collect()
+        })
+        .collect();
+    // The example doesn't draw letters, so show the picture when they can't be read.
+    Ok(match ocr::recognize(&crt) {
+        Ok(letters) => letters.into(),
+        Err(_) => crt.into(),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn unknown_instruction_is_an_error() {
+        let err = solve_1("noop\naddx 3\nmulx 2").unwrap_err();
+        let err = err.downcast_ref::<ParseError>().unwrap();
+
+        assert_eq!(err.line, 3);
+        assert_eq!(err.text, "mulx 2");
+    }
+}
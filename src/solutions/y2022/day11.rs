@@ -0,0 +1,177 @@
+use crate::{answer::Answer, error::ParseError, input};
+use anyhow::{anyhow, Result};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{multispace0, u64},
+    combinator::{map, value},
+    multi::separated_list1,
+    sequence::{delimited, preceded, tuple},
+    IResult,
+};
+
+pub const DAY: u32 = 11;
+pub const YEAR: u32 = 2022;
+
+crate::solution!("Monkey in the Middle", parse = parse);
+
+#[derive(Clone, Copy, Debug)]
+enum Operand {
+    Old,
+    Value(u64),
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Operation {
+    Add(Operand),
+    Mul(Operand),
+}
+
+impl Operation {
+    fn apply(&self, old: u64) -> u64 {
+        let operand = |o: &Operand| match o {
+            Operand::Old => old,
+            Operand::Value(v) => *v,
+        };
+        match self {
+            Operation::Add(o) => old + operand(o),
+            Operation::Mul(o) => old * operand(o),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Monkey {
+    starting_items: Vec<u64>,
+    operation: Operation,
+    div_test: u64,
+    true_throw: usize,
+    false_throw: usize,
+}
+
+fn parse_monkey_nr(input: &str) -> IResult<&str, u64> {
+    delimited(tag("Monkey "), u64, tag(":"))(input)
+}
+
+fn parse_operand(input: &str) -> IResult<&str, Operand> {
+    alt((value(Operand::Old, tag("old")), map(u64, Operand::Value)))(input)
+}
+
+fn parse_operation(input: &str) -> IResult<&str, Operation> {
+    let (input, _) = tag("Operation: new = old ")(input)?;
+    alt((
+        map(preceded(tag("+ "), parse_operand), Operation::Add),
+        map(preceded(tag("* "), parse_operand), Operation::Mul),
+    ))(input)
+}
+
+fn parse_monkey(input: &str) -> IResult<&str, Monkey> {
+    let (input, _) = parse_monkey_nr(input)?;
+    let (input, starting_items) = preceded(
+        tuple((multispace0, tag("Starting items: "))),
+        separated_list1(tag(", "), u64),
+    )(input)?;
+    let (input, operation) = preceded(multispace0, parse_operation)(input)?;
+    let (input, div_test) = preceded(tuple((multispace0, tag("Test: divisible by "))), u64)(input)?;
+    let (input, true_throw) =
+        preceded(tuple((multispace0, tag("If true: throw to monkey "))), u64)(input)?;
+    let (input, false_throw) =
+        preceded(tuple((multispace0, tag("If false: throw to monkey "))), u64)(input)?;
+    Ok((
+        input,
+        Monkey {
+            starting_items,
+            operation,
+            div_test,
+            true_throw: true_throw as usize,
+            false_throw: false_throw as usize,
+        },
+    ))
+}
+
+fn parse(input: &str) -> Result<Vec<Monkey>> {
+    input::groups(input)
+        .into_iter()
+        .map(|group| {
+            let (_, monkey) = parse_monkey(group).map_err(|e| ParseError::from_nom(group, e))?;
+            Ok(monkey)
+        })
+        .collect()
+}
+
+fn solve(input: &str, rounds: usize, relief: bool) -> Result<Answer> {
+    let monkeys = parse(input)?;
+    // only divisibility matters, so without relief worry levels can be kept modulo the
+    // product of the tests. Dividing by 3 does not survive that, so part 1 keeps them whole.
+    let modulus: u64 = monkeys.iter().map(|m| m.div_test).product();
+    let mut items: Vec<Vec<u64>> = monkeys.iter().map(|m| m.starting_items.clone()).collect();
+    let mut inspected = vec![0usize; monkeys.len()];
+    for _ in 0..rounds {
+        for (i, monkey) in monkeys.iter().enumerate() {
+            let held = std::mem::take(&mut items[i]);
+            inspected[i] += held.len();
+            for item in held {
+                let mut worry = monkey.operation.apply(item);
+                if relief {
+                    worry /= 3;
+                } else {
+                    worry %= modulus;
+                }
+                let target = if worry % monkey.div_test == 0 {
+                    monkey.true_throw
+                } else {
+                    monkey.false_throw
+                };
+                items
+                    .get_mut(target)
+                    .ok_or_else(|| anyhow!("monkey {} does not exist", target))?
+                    .push(worry);
+            }
+        }
+    }
+    inspected.sort_by(|a, b| b.cmp(a));
+    let res: usize = inspected.iter().take(2).product();
+    Ok(res.into())
+}
+
+pub fn solve_1(input: &str) -> Result<Answer> {
+    solve(input, 20, true)
+}
+
+pub fn solve_2(input: &str) -> Result<Answer> {
+    solve(input, 10_000, false)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // The product of the tests is 30, which 100 exceeds from the start
+    const LARGE_WORRY: &str = "\
+Monkey 0:
+  Starting items: 100
+  Operation: new = old * 1
+  Test: divisible by 2
+    If true: throw to monkey 1
+    If false: throw to monkey 1
+
+Monkey 1:
+  Starting items: 7
+  Operation: new = old * 1
+  Test: divisible by 3
+    If true: throw to monkey 0
+    If false: throw to monkey 2
+
+Monkey 2:
+  Starting items: 5
+  Operation: new = old + 1
+  Test: divisible by 5
+    If true: throw to monkey 0
+    If false: throw to monkey 2
+";
+
+    #[test]
+    fn relief_keeps_whole_worry_levels() {
+        assert_eq!(solve_1(LARGE_WORRY).unwrap().to_string(), "2862");
+    }
+}
@@ -0,0 +1,105 @@
+use crate::{
+    answer::Answer,
+    grid::{Grid, Pos},
+    search::{self, Path},
+    solution::Part,
+};
+use anyhow::{anyhow, Result};
+
+pub const DAY: u32 = 12;
+pub const YEAR: u32 = 2022;
+
+crate::solution!(
+    "Hill Climbing Algorithm",
+    parse = parse,
+    visualize = draw_path
+);
+
+/// The heights of the area, 0 for `a` up to 25 for `z`.
+#[derive(Clone, Debug)]
+pub struct Heightmap {
+    pub heights: Grid<u8>,
+    /// Where `S` is, at height 0
+    pub start: Pos,
+    /// Where `E` is, at height 25
+    pub end: Pos,
+}
+
+pub fn parse(input: &str) -> Result<Heightmap> {
+    let cells = Grid::parse(input, |c| matches!(c, 'a'..='z' | 'S' | 'E').then_some(c))?;
+    let find = |marker: char| {
+        cells
+            .position(|&c| c == marker)
+            .ok_or_else(|| anyhow!("the heightmap has no {}", marker))
+    };
+    let (start, end) = (find('S')?, find('E')?);
+    let heights = cells.map(|&c| match c {
+        'S' => 0,
+        'E' => 25,
+        c => c as u8 - b'a',
+    });
+    Ok(Heightmap {
+        heights,
+        start,
+        end,
+    })
+}
+
+/// The shortest way up to `E`, from `S` for part 1 and from any lowest square for part 2.
+/// A step may go down any number of levels, but up only one.
+fn climb(map: &Heightmap, part: Part) -> Result<Path<usize>> {
+    let heights = &map.heights;
+    let sources: Vec<Pos> = match part {
+        Part::One => vec![map.start],
+        Part::Two => heights.positions().filter(|&p| heights[p] == 0).collect(),
+    };
+    search::bfs(
+        heights,
+        sources,
+        |from, to| heights[to] <= heights[from] + 1,
+        |p| p == map.end,
+    )
+    .ok_or_else(|| anyhow!("there is no way up to E"))
+}
+
+/// The map with the path drawn over it, every square on it showing where the next
+/// step goes.
+pub fn draw_path(part: Part, input: &str) -> Result<String> {
+    let map = parse(input)?;
+    let path = climb(&map, part)?;
+    let mut drawing = Grid::parse(input, Some)?;
+    for step in path.cells.windows(2) {
+        let (from, to) = (step[0], step[1]);
+        drawing[from] = match () {
+            _ if to.0 > from.0 => '>',
+            _ if to.0 < from.0 => '<',
+            _ if to.1 > from.1 => 'v',
+            _ => '^',
+        };
+    }
+    Ok(drawing.to_string())
+}
+
+pub fn solve_1(input: &str) -> Result<Answer> {
+    Ok(climb(&parse(input)?, Part::One)?.steps().into())
+}
+
+pub fn solve_2(input: &str) -> Result<Answer> {
+    Ok(climb(&parse(input)?, Part::Two)?.steps().into())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn draw_example_path() {
+        let input = std::fs::read_to_string(crate::input::data_path(YEAR, DAY, "example")).unwrap();
+        let drawing = draw_path(Part::One, &input).unwrap();
+        assert_eq!(drawing, ">>vv<<<<\nabvvv<<^\nacvv>E^^\nacv>>>^^\nab>>>>>^");
+        // The squares off the path keep their height
+        let drawing = draw_path(Part::Two, &input).unwrap();
+        assert_eq!(drawing.lines().next(), Some("Sabv<<<<"));
+        assert!(draw_path(Part::One, "Szz\nzzE\n").is_err());
+    }
+}
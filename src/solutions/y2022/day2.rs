@@ -0,0 +1,50 @@
+use crate::answer::Answer;
+use anyhow::Result;
+
+pub const DAY: u32 = 2;
+pub const YEAR: u32 = 2022;
+
+crate::solution!("Rock Paper Scissors");
+
+fn scores_1(round: &str) -> u32 {
+    match round {
+        "A X" => 4,
+        "A Y" => 8,
+        "A Z" => 3,
+        "B X" => 1,
+        "B Y" => 5,
+        "B Z" => 9,
+        "C X" => 7,
+        "C Y" => 2,
+        "C Z" => 6,
+        _ => 0,
+    }
+}
+
+fn scores_2(round: &str) -> u32 {
+    match round {
+        "A X" => 3,
+        "A Y" => 4,
+        "A Z" => 8,
+        "B X" => 1,
+        "B Y" => 5,
+        "B Z" => 9,
+        "C X" => 2,
+        "C Y" => 6,
+        "C Z" => 7,
+        _ => 0,
+    }
+}
+
+fn solve(input: &str, scores: fn(&str) -> u32) -> Result<Answer> {
+    let res: u32 = input.lines().map(scores).sum();
+    Ok(res.into())
+}
+
+pub fn solve_1(input: &str) -> Result<Answer> {
+    solve(input, scores_1)
+}
+
+pub fn solve_2(input: &str) -> Result<Answer> {
+    solve(input, scores_2)
+}
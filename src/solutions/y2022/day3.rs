@@ -0,0 +1,55 @@
+use crate::answer::Answer;
+use anyhow::Result;
+use std::collections::HashSet;
+
+pub const DAY: u32 = 3;
+pub const YEAR: u32 = 2022;
+
+crate::solution!("Rucksack Reorganization");
+
+pub fn solve_1(input: &str) -> Result<Answer> {
+    let res: u32 = input
+        .lines()
+        .map(|l| {
+            let len = l.len();
+            let first = l[..(len / 2 + 1)].to_string();
+            let second = l[(len / 2)..].to_string();
+            let duplicate = first
+                .chars()
+                .find(|c| second.chars().find(|ch| ch == c).is_some());
+            if let Some(dup) = duplicate {
+                if dup.is_ascii_lowercase() {
+                    return dup as u32 - 'a' as u32 + 1;
+                } else {
+                    return dup as u32 - 'A' as u32 + 27;
+                }
+            }
+            0
+        })
+        .sum();
+    Ok(res.into())
+}
+
+pub fn solve_2(input: &str) -> Result<Answer> {
+    let res: u32 = input
+        .lines()
+        .collect::<Vec<&str>>()
+        .chunks(3)
+        .map(|group| {
+            let set: HashSet<char> = group[0]
+                .chars()
+                .filter(|c| group[1].chars().find(|ch| ch == c).is_some())
+                .collect();
+            let duplicate = group[2].chars().find(|c| set.contains(c));
+            if let Some(dup) = duplicate {
+                if dup.is_ascii_lowercase() {
+                    return dup as u32 - 'a' as u32 + 1;
+                } else {
+                    return dup as u32 - 'A' as u32 + 27;
+                }
+            }
+            0
+        })
+        .sum();
+    Ok(res.into())
+}
@@ -0,0 +1,36 @@
+use crate::{answer::Answer, input};
+use anyhow::Result;
+
+pub const DAY: u32 = 4;
+pub const YEAR: u32 = 2022;
+
+crate::solution!("Camp Cleanup");
+
+type Pair = input::Pair<u32>;
+
+fn check_pairs_1(first: &Pair, second: &Pair) -> bool {
+    (first.0 >= second.0 && first.1 <= second.1) || (second.0 >= first.0 && second.1 <= first.1)
+}
+
+fn check_pairs_2(first: &Pair, second: &Pair) -> bool {
+    first.0 == second.0
+        || (first.1 <= second.1 && first.1 >= second.0)
+        || (second.0 >= first.0 && second.0 <= first.1)
+        || (second.1 <= first.1 && second.1 >= first.0)
+}
+
+fn solve(input: &str, check_pairs: fn(first: &Pair, second: &Pair) -> bool) -> Result<Answer> {
+    let res = input::pairs::<u32>(input, ",", "-")?
+        .iter()
+        .filter(|(first, second)| check_pairs(first, second))
+        .count();
+    Ok(res.into())
+}
+
+pub fn solve_1(input: &str) -> Result<Answer> {
+    solve(input, check_pairs_1)
+}
+
+pub fn solve_2(input: &str) -> Result<Answer> {
+    solve(input, check_pairs_2)
+}
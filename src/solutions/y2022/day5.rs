@@ -0,0 +1,108 @@
+use crate::{answer::Answer, error::ParseError, input};
+use anyhow::Result;
+use std::str::{from_utf8, FromStr};
+
+pub const DAY: u32 = 5;
+pub const YEAR: u32 = 2022;
+
+crate::solution!("Supply Stacks");
+
+struct Move {
+    amount: usize,
+    from: usize,
+    to: usize,
+}
+
+impl FromStr for Move {
+    type Err = ParseError;
+
+    // move 1 from 2 to 1
+    fn from_str(move_line: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<&str> = move_line.split(' ').collect();
+        if parts.len() != 6 || parts[0] != "move" || parts[2] != "from" || parts[4] != "to" {
+            return Err(ParseError::at(
+                move_line,
+                move_line,
+                "expected \"move <amount> from <stack> to <stack>\"",
+            ));
+        }
+        let amount: usize = input::parse_at(move_line, parts[1])?;
+        let from: usize = input::parse_at(move_line, parts[3])?;
+        let to: usize = input::parse_at(move_line, parts[5])?;
+        Ok(Move { amount, from, to })
+    }
+}
+
+fn get_items_part1(stack: &mut Vec<char>, len: usize) -> Vec<char> {
+    let items: Vec<char> = stack.drain(len..).rev().collect();
+    items
+}
+
+fn get_items_part2(stack: &mut Vec<char>, len: usize) -> Vec<char> {
+    let items: Vec<char> = stack.drain(len..).collect();
+    items
+}
+
+fn solve(
+    input: &str,
+    get_items: fn(stack: &mut Vec<char>, len: usize) -> Vec<char>,
+) -> Result<Answer> {
+    let [pre_stacks, pre_moves] = input::sections(input)?;
+    let nr_stacks = pre_stacks
+        .lines()
+        .last()
+        .map_or(0, |numbers| numbers.split_whitespace().count());
+    let moves: Vec<Move> = input::lines(pre_moves)?;
+    let mut stacks: Vec<Vec<char>> = vec![Vec::new(); nr_stacks];
+
+    pre_stacks.lines().rev().skip(1).for_each(|l| {
+        let parts: Vec<&str> = l
+            .as_bytes()
+            .chunks(4)
+            .map(|l| from_utf8(l).unwrap())
+            .collect();
+        parts.iter().enumerate().for_each(|(i, &p)| {
+            let c: char = p.chars().nth(1).unwrap();
+            if c != ' ' {
+                stacks[i].push(c);
+            }
+        })
+    });
+    moves.iter().for_each(|m| {
+        let new_len = stacks[m.from - 1].len() - m.amount;
+        let mut items = get_items(&mut stacks[m.from - 1], new_len);
+        stacks[m.to - 1].append(&mut items);
+    });
+    let res: String = stacks.iter_mut().map(|s| s.pop().unwrap()).collect();
+    Ok(res.into())
+}
+
+pub fn solve_1(input: &str) -> Result<Answer> {
+    solve(input, get_items_part1)
+}
+
+pub fn solve_2(input: &str) -> Result<Answer> {
+    solve(input, get_items_part2)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::solution::{Part, Solution};
+
+    fn example_input() -> String {
+        std::fs::read_to_string(input::data_path(YEAR, DAY, "example")).unwrap()
+    }
+
+    #[test]
+    fn broken_move_points_at_line() {
+        let input = example_input().replace("move 2 from 2", "move two from 2");
+
+        let err = Puzzle.solve(Part::One, &input).unwrap_err();
+        let err = err.downcast_ref::<ParseError>().unwrap();
+
+        assert_eq!((err.year, err.day), (Some(YEAR), Some(DAY)));
+        assert_eq!((err.line, err.column), (8, 6));
+        assert_eq!(err.text, "move two from 2 to 1");
+    }
+}
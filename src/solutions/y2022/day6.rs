@@ -0,0 +1,31 @@
+use crate::answer::Answer;
+use anyhow::{bail, Result};
+use std::collections::{HashSet, VecDeque};
+
+pub const DAY: u32 = 6;
+pub const YEAR: u32 = 2022;
+
+crate::solution!("Tuning Trouble");
+
+fn solve(input: &str, nr_unique: usize) -> Result<Answer> {
+    let mut som: VecDeque<char> = VecDeque::new();
+    for (i, d) in input.chars().enumerate() {
+        som.push_back(d);
+        if som.len() == nr_unique {
+            let mut unique = HashSet::new();
+            if som.iter().all(|x| unique.insert(x)) {
+                return Ok((i + 1).into());
+            }
+            som.pop_front();
+        }
+    }
+    bail!("no marker of {} unique characters found", nr_unique)
+}
+
+pub fn solve_1(input: &str) -> Result<Answer> {
+    solve(input, 4)
+}
+
+pub fn solve_2(input: &str) -> Result<Answer> {
+    solve(input, 14)
+}
@@ -0,0 +1,60 @@
+use crate::{
+    answer::Answer,
+    geometry::Direction,
+    grid::{Grid, Pos},
+};
+use anyhow::Result;
+
+pub const DAY: u32 = 8;
+pub const YEAR: u32 = 2022;
+
+crate::solution!("Treetop Tree House");
+
+fn parse(input: &str) -> Result<Grid<u8>> {
+    Grid::parse(input, |c| c.to_digit(10).map(|d| d as u8))
+}
+
+/// Whether the tree at `pos` can be seen from outside the grid, looking in `direction`.
+fn visible(trees: &Grid<u8>, pos: Pos, direction: Direction) -> bool {
+    trees.ray(pos, direction).all(|p| trees[p] < trees[pos])
+}
+
+/// How many trees can be seen from the tree at `pos`, looking in `direction`.
+fn viewing_distance(trees: &Grid<u8>, pos: Pos, direction: Direction) -> usize {
+    let mut seen = 0;
+    for p in trees.ray(pos, direction) {
+        seen += 1;
+        if trees[p] >= trees[pos] {
+            break;
+        }
+    }
+    seen
+}
+
+pub fn solve_1(input: &str) -> Result<Answer> {
+    let trees = parse(input)?;
+    let count = trees
+        .positions()
+        .filter(|&pos| {
+            Direction::ORTHOGONAL
+                .iter()
+                .any(|&d| visible(&trees, pos, d))
+        })
+        .count();
+    Ok(count.into())
+}
+
+pub fn solve_2(input: &str) -> Result<Answer> {
+    let trees = parse(input)?;
+    let best = trees
+        .positions()
+        .map(|pos| {
+            Direction::ORTHOGONAL
+                .iter()
+                .map(|&d| viewing_distance(&trees, pos, d))
+                .product::<usize>()
+        })
+        .max()
+        .unwrap_or(0);
+    Ok(best.into())
+}
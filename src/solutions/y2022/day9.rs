@@ -0,0 +1,65 @@
+use crate::{
+    answer::Answer,
+    error::ParseError,
+    geometry::{Coord, Direction},
+    input,
+};
+use anyhow::Result;
+use std::collections::HashSet;
+
+pub const DAY: u32 = 9;
+pub const YEAR: u32 = 2022;
+
+crate::solution!("Rope Bridge", parse = parse_moves);
+
+/// Where a knot ends up when the knot in front of it has moved to `head`.
+fn follow(knot: Coord, head: Coord) -> Coord {
+    if knot.chebyshev(head) > 1 {
+        knot + (head - knot).signum()
+    } else {
+        knot
+    }
+}
+
+fn parse_moves(input: &str) -> Result<Vec<(Direction, u32)>> {
+    input::lines_with(input, |line| {
+        let (direction, count) = line
+            .split_once(' ')
+            .ok_or_else(|| ParseError::at(line, line, "expected \"<direction> <count>\""))?;
+        let direction = match direction {
+            "U" => Direction::Up,
+            "D" => Direction::Down,
+            "R" => Direction::Right,
+            "L" => Direction::Left,
+            _ => {
+                return Err(ParseError::at(line, direction, "expected one of U, D, R or L").into())
+            }
+        };
+        Ok((direction, input::parse_at(line, count)?))
+    })
+}
+
+/// The number of positions the last of `knots` visits.
+fn simulate(input: &str, knots: usize) -> Result<Answer> {
+    let moves = parse_moves(input)?;
+    let mut rope = vec![Coord::ORIGIN; knots];
+    let mut visited = HashSet::from([Coord::ORIGIN]);
+    for (direction, count) in moves {
+        for _ in 0..count {
+            rope[0] = rope[0].step(direction);
+            for i in 1..knots {
+                rope[i] = follow(rope[i], rope[i - 1]);
+            }
+            visited.insert(rope[knots - 1]);
+        }
+    }
+    Ok(visited.len().into())
+}
+
+pub fn solve_1(input: &str) -> Result<Answer> {
+    simulate(input, 2)
+}
+
+pub fn solve_2(input: &str) -> Result<Answer> {
+    simulate(input, 10)
+}
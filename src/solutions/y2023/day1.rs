@@ -0,0 +1,71 @@
+use crate::answer::Answer;
+use anyhow::Result;
+
+pub const DAY: u32 = 1;
+pub const YEAR: u32 = 2023;
+
+crate::solution!("Trebuchet?!");
+
+fn solve(input: &str) -> Result<Answer> {
+    let result = input
+        .lines()
+        .map(|s| {
+            let first_digit = s.chars().filter_map(|c| c.to_digit(10)).next().unwrap();
+            let last_digit = s
+                .chars()
+                .filter_map(|c| c.to_digit(10))
+                .next_back()
+                .unwrap();
+            (first_digit, last_digit)
+        })
+        .fold(0, |acc, i| acc + (i.0 * 10) + i.1);
+    Ok(result.into())
+}
+
+fn convert_to_digit(line: &str) -> u32 {
+    let conversion = [
+        ("one", 1),
+        ("two", 2),
+        ("three", 3),
+        ("four", 4),
+        ("five", 5),
+        ("six", 6),
+        ("seven", 7),
+        ("eight", 8),
+        ("nine", 9),
+        ("1", 1),
+        ("2", 2),
+        ("3", 3),
+        ("4", 4),
+        ("5", 5),
+        ("6", 6),
+        ("7", 7),
+        ("8", 8),
+        ("9", 9),
+    ];
+    if let Some(found) = conversion
+        .iter()
+        .filter_map(|(s, n)| line.find(s).map(|found| (found, s, *n)))
+        .min_by(|a, b| a.0.cmp(&b.0))
+    {
+        let first = found.2;
+        if let Some(found) = conversion
+            .iter()
+            .filter_map(|(s, n)| line.rfind(s).map(|found| (found, s, *n)))
+            .max_by(|a, b| a.0.cmp(&b.0))
+        {
+            let second = found.2;
+            return (first * 10) + second;
+        }
+    }
+    0
+}
+
+pub fn solve_1(input: &str) -> Result<Answer> {
+    solve(input)
+}
+
+pub fn solve_2(input: &str) -> Result<Answer> {
+    let result: u32 = input.lines().map(convert_to_digit).sum();
+    Ok(result.into())
+}
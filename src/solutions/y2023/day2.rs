@@ -0,0 +1,157 @@
+use crate::{answer::Answer, error::ParseError};
+use anyhow::Result;
+use nom::{
+    bytes::complete::tag,
+    character::complete::{alpha1, digit1, line_ending, u32},
+    combinator::map_res,
+    multi::separated_list1,
+    sequence::{preceded, separated_pair},
+    IResult,
+};
+
+pub const DAY: u32 = 2;
+pub const YEAR: u32 = 2023;
+
+crate::solution!("Cube Conundrum", parse = parse_games);
+
+#[derive(Debug, Clone, Copy)]
+struct Cube<'a> {
+    color: &'a str,
+    count: u32,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, PartialOrd, Ord)]
+struct Round {
+    red: u32,
+    green: u32,
+    blue: u32,
+}
+
+#[derive(Debug, Clone)]
+struct Game {
+    id: usize,
+    rounds: Vec<Round>,
+}
+
+fn get_cube_count(cubes: &[Cube], color: &str) -> u32 {
+    cubes
+        .iter()
+        .find(|cube| cube.color == color)
+        .unwrap_or(&Cube {
+            color: "",
+            count: 0,
+        })
+        .count
+}
+
+fn cube(input: &str) -> IResult<&str, Cube<'_>> {
+    let (input, (count, color)) = separated_pair(u32, tag(" "), alpha1)(input)?;
+    Ok((input, Cube { color, count }))
+}
+
+fn round(input: &str) -> IResult<&str, Round> {
+    let (input, cubes) = separated_list1(tag(", "), cube)(input)?;
+    let round = Round {
+        red: get_cube_count(&cubes, "red"),
+        green: get_cube_count(&cubes, "green"),
+        blue: get_cube_count(&cubes, "blue"),
+    };
+    Ok((input, round))
+}
+
+// Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+fn game(input: &str) -> IResult<&str, Game> {
+    let (input, id) = preceded(tag("Game "), map_res(digit1, str::parse))(input)?;
+    let (input, rounds) = preceded(tag(": "), separated_list1(tag("; "), round))(input)?;
+    Ok((input, Game { id, rounds }))
+}
+
+fn parse_games(input: &str) -> Result<Vec<Game>> {
+    let (rest, games) =
+        separated_list1(line_ending, game)(input).map_err(|e| ParseError::from_nom(input, e))?;
+    let rest = rest.trim_start();
+    if !rest.is_empty() {
+        // the list stops at the first line that isn't a game, parse it again for the details
+        let err = match game(rest) {
+            Err(e) => ParseError::from_nom(input, e),
+            Ok(_) => ParseError::at(input, rest, "unexpected input"),
+        };
+        return Err(err.into());
+    }
+    Ok(games)
+}
+
+fn solve(input: &str) -> Result<Answer> {
+    let games = parse_games(input)?;
+    let result: u32 = games
+        .iter()
+        .map(|game| {
+            let red = game
+                .rounds
+                .iter()
+                .max_by(|a, b| a.red.cmp(&b.red))
+                .unwrap()
+                .red;
+            let green = game
+                .rounds
+                .iter()
+                .max_by(|a, b| a.green.cmp(&b.green))
+                .unwrap()
+                .green;
+            let blue = game
+                .rounds
+                .iter()
+                .max_by(|a, b| a.blue.cmp(&b.blue))
+                .unwrap()
+                .blue;
+            red * green * blue
+        })
+        .sum();
+    Ok(result.into())
+}
+
+pub fn solve_1(input: &str) -> Result<Answer> {
+    let games = parse_games(input)?;
+    let compare = Round {
+        red: 12,
+        green: 13,
+        blue: 14,
+    };
+    let result: usize = games
+        .iter()
+        .filter(|game| {
+            game.rounds.iter().all(|round| {
+                round.red <= compare.red
+                    && round.blue <= compare.blue
+                    && round.green <= compare.green
+            })
+        })
+        .map(|game| game.id)
+        .sum();
+    Ok(result.into())
+}
+
+pub fn solve_2(input: &str) -> Result<Answer> {
+    solve(input)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn example_input() -> String {
+        std::fs::read_to_string(crate::input::data_path(YEAR, DAY, "example")).unwrap()
+    }
+
+    #[test]
+    fn broken_game_points_at_line() {
+        let input = example_input().replace("Game 3: 8 green", "Game 3: eight green");
+
+        let err = solve_1(&input)
+            .unwrap_err()
+            .downcast::<ParseError>()
+            .unwrap();
+
+        assert_eq!((err.line, err.column), (3, 9));
+    }
+}
@@ -0,0 +1,74 @@
+use crate::{
+    answer::Answer,
+    grid::{Grid, Pos},
+};
+use anyhow::Result;
+
+pub const DAY: u32 = 3;
+pub const YEAR: u32 = 2023;
+
+crate::solution!("Gear Ratios");
+
+#[derive(Debug)]
+struct Part {
+    number: u32,
+    symbol_min: Pos,
+    symbol_max: Pos,
+}
+
+impl Part {
+    /// The number made of `digits`, with the box around them where a symbol counts.
+    fn new(digits: &[(u32, Pos)]) -> Self {
+        let ((_, (x_min, y)), (_, (x_max, _))) = (digits[0], digits[digits.len() - 1]);
+        Part {
+            number: digits.iter().fold(0, |n, &(d, _)| n * 10 + d),
+            symbol_min: (x_min.saturating_sub(1), y.saturating_sub(1)),
+            symbol_max: (x_max + 1, y + 1),
+        }
+    }
+}
+
+fn is_symbol(c: char) -> bool {
+    !c.is_ascii_digit() && c != '.'
+}
+
+fn solve(input: &str) -> Result<Answer> {
+    let schematic = Grid::parse(input, Some)?;
+    let symbols: Vec<Pos> = schematic
+        .positions()
+        .filter(|&p| is_symbol(schematic[p]))
+        .collect();
+    let digits: Vec<(u32, Pos)> = schematic
+        .iter()
+        .filter_map(|(p, c)| Some((c.to_digit(10)?, p)))
+        .collect();
+    let follows = |a: &(u32, Pos), b: &(u32, Pos)| a.1 .1 == b.1 .1 && a.1 .0 + 1 == b.1 .0;
+    let result = digits
+        .windows(3)
+        .map(|wind| {
+            if follows(&wind[0], &wind[1]) && follows(&wind[1], &wind[2]) {
+                return Part::new(wind);
+            }
+            if follows(&wind[0], &wind[1]) {
+                return Part::new(&wind[..2]);
+            }
+            Part::new(&wind[..1])
+        })
+        .filter(|part| {
+            symbols.iter().any(|&(x, y)| {
+                (part.symbol_min.0..=part.symbol_max.0).contains(&x)
+                    && (part.symbol_min.1..=part.symbol_max.1).contains(&y)
+            })
+        })
+        .map(|part| part.number)
+        .sum::<u32>();
+    Ok(result.into())
+}
+
+pub fn solve_1(input: &str) -> Result<Answer> {
+    solve(input)
+}
+
+pub fn solve_2(input: &str) -> Result<Answer> {
+    solve(input)
+}
@@ -0,0 +1,3 @@
+pub mod day1;
+pub mod day2;
+pub mod day3;
@@ -0,0 +1,405 @@
+//! Submits answers and keeps track of what the site said about them.
+//!
+//! Every submission is appended to `data/<year>/<day>.submissions`, one per line:
+//! ```text
+//! 1 too-high 5000
+//! 1 correct 4361
+//! ```
+//! Correct answers also go into the answer store of [`crate::answers`].
+
+use crate::{
+    answers::{answers_path, Answers},
+    client::{Client, Config},
+    input,
+    solution::Part,
+};
+use anyhow::{anyhow, bail, Context, Result};
+use std::{
+    fmt,
+    io::Write,
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::Duration,
+};
+
+pub fn submissions_path(year: u32, day: u32) -> String {
+    input::data_path(year, day, "submissions")
+}
+
+/// What the site said about a submitted answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Incorrect,
+    TooHigh,
+    TooLow,
+    /// Submitted too soon after the previous answer, with the time left to wait
+    RateLimited(Option<Duration>),
+    /// The part was solved before
+    AlreadySolved,
+}
+
+impl Verdict {
+    /// Reads the verdict from the page the site answers a submission with.
+    pub fn from_page(page: &str) -> Result<Verdict> {
+        Ok(if page.contains("That's the right answer") {
+            Verdict::Correct
+        } else if page.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if page.contains("your answer is too low") {
+            Verdict::TooLow
+        } else if page.contains("That's not the right answer") {
+            Verdict::Incorrect
+        } else if page.contains("You gave an answer too recently") {
+            Verdict::RateLimited(wait_time(page))
+        } else if page.contains("Did you already complete it?") {
+            Verdict::AlreadySolved
+        } else {
+            bail!("could not make sense of the answer page")
+        })
+    }
+
+    /// Whether the answer is known to be wrong.
+    pub fn is_wrong(&self) -> bool {
+        matches!(
+            self,
+            Verdict::Incorrect | Verdict::TooHigh | Verdict::TooLow
+        )
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::Incorrect => "incorrect",
+            Verdict::TooHigh => "too-high",
+            Verdict::TooLow => "too-low",
+            Verdict::RateLimited(_) => "rate-limited",
+            Verdict::AlreadySolved => "already-solved",
+        }
+    }
+}
+
+/// Reads "You have 1m 5s left to wait" from a page, or None for anything else.
+fn wait_time(page: &str) -> Option<Duration> {
+    let before = &page[..page.find(" left to wait")?];
+    let time = &before[before.rfind("have ")? + "have ".len()..];
+    if time.trim().is_empty() {
+        return None;
+    }
+    let mut seconds: u64 = 0;
+    for part in time.split_whitespace() {
+        let (i, unit) = part.char_indices().last()?;
+        let number: u64 = part[..i].parse().ok()?;
+        let unit = match unit {
+            'h' => 3600,
+            'm' => 60,
+            's' => 1,
+            _ => return None,
+        };
+        seconds = seconds.checked_add(number.checked_mul(unit)?)?;
+    }
+    Some(Duration::from_secs(seconds))
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::RateLimited(Some(wait)) => {
+                write!(f, "rate limited, wait {}s", wait.as_secs())
+            }
+            verdict => write!(f, "{}", verdict.name().replace('-', " ")),
+        }
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Ok(match s {
+            "correct" => Verdict::Correct,
+            "incorrect" => Verdict::Incorrect,
+            "too-high" => Verdict::TooHigh,
+            "too-low" => Verdict::TooLow,
+            "rate-limited" => Verdict::RateLimited(None),
+            "already-solved" => Verdict::AlreadySolved,
+            _ => bail!("unknown verdict {:?}", s),
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Submission {
+    pub part: Part,
+    pub verdict: Verdict,
+    pub answer: String,
+}
+
+/// All earlier submissions of one puzzle.
+#[derive(Clone, Debug, Default)]
+pub struct History {
+    pub submissions: Vec<Submission>,
+}
+
+impl History {
+    pub fn load_from(path: &Path) -> Result<History> {
+        let text = match std::fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(History::default()),
+            Err(e) => return Err(e).with_context(|| format!("could not read {}", path.display())),
+        };
+        let submissions = input::lines_with(&text, |line| {
+            let mut fields = line.splitn(3, ' ');
+            let part = fields.next().and_then(|p| p.parse().ok());
+            let part = part
+                .and_then(Part::from_number)
+                .ok_or_else(|| anyhow!("expected a part number"))?;
+            Ok(Submission {
+                part,
+                verdict: fields.next().unwrap_or_default().parse()?,
+                answer: fields.next().unwrap_or_default().to_string(),
+            })
+        })
+        .with_context(|| format!("could not read {}", path.display()))?;
+        Ok(History { submissions })
+    }
+
+    /// Appends `submission` to the file at `path`.
+    pub fn record(path: &Path, submission: &Submission) -> Result<()> {
+        std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .and_then(|mut file| {
+                writeln!(
+                    file,
+                    "{} {} {}",
+                    submission.part,
+                    submission.verdict.name(),
+                    submission.answer
+                )
+            })
+            .with_context(|| format!("could not write {}", path.display()))
+    }
+
+    /// Why `answer` should not be submitted for `part`, if it is known to be wrong.
+    pub fn refusal(&self, part: Part, answer: &str) -> Option<String> {
+        let earlier = self.submissions.iter().filter(|s| s.part == part);
+        let number = answer.parse::<i128>().ok();
+        for submission in earlier {
+            let before = submission.answer.parse::<i128>().ok();
+            match (submission.verdict, number, before) {
+                (Verdict::Correct, _, _) | (Verdict::AlreadySolved, _, _) => {
+                    return Some(format!("part {} is already solved", part))
+                }
+                (verdict, _, _) if verdict.is_wrong() && submission.answer == answer => {
+                    return Some(format!("{} was already rejected as {}", answer, verdict))
+                }
+                (Verdict::TooHigh, Some(n), Some(high)) if n >= high => {
+                    return Some(format!("{} was too high, so {} is too", high, n))
+                }
+                (Verdict::TooLow, Some(n), Some(low)) if n <= low => {
+                    return Some(format!("{} was too low, so {} is too", low, n))
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+}
+
+/// Submits `answer` for a part, unless it is known to be wrong.
+pub fn submit(config: &Config, year: u32, day: u32, part: Part, answer: &str) -> Result<Verdict> {
+    let files = Files {
+        submissions: submissions_path(year, day).into(),
+        answers: answers_path(year, day).into(),
+    };
+    submit_to(&Client::new(config)?, year, day, part, answer, &files)
+}
+
+/// Where [`submit_to`] keeps track of submissions and accepted answers.
+struct Files {
+    submissions: PathBuf,
+    answers: PathBuf,
+}
+
+fn submit_to(
+    client: &Client,
+    year: u32,
+    day: u32,
+    part: Part,
+    answer: &str,
+    files: &Files,
+) -> Result<Verdict> {
+    let answer = answer.trim();
+    if answer.is_empty() || answer.contains('\n') {
+        bail!("{:?} cannot be submitted", answer);
+    }
+    let mut answers = Answers::load_from(&files.answers)?;
+    if let Some(accepted) = answers.get(part).filter(|_| answers.is_verified(part)) {
+        bail!("part {} was already solved with {}", part, accepted);
+    }
+    if let Some(reason) = History::load_from(&files.submissions)?.refusal(part, answer) {
+        bail!("not submitting: {}", reason);
+    }
+
+    let page = client.post(
+        &format!("/{}/day/{}/answer", year, day),
+        &[("level", &part.to_string()), ("answer", answer)],
+    )?;
+    let verdict = Verdict::from_page(&page)?;
+    let submission = Submission {
+        part,
+        verdict,
+        answer: answer.to_string(),
+    };
+    History::record(&files.submissions, &submission)?;
+    if verdict == Verdict::Correct {
+        answers.set(part, answer.parse().unwrap())?;
+        answers.save_to(&files.answers)?;
+    }
+    Ok(verdict)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::client::mock;
+
+    fn page(text: &str) -> String {
+        format!(
+            "<html><main><article><p>{}</p></article></main></html>",
+            text
+        )
+    }
+
+    fn temp_files(name: &str) -> Files {
+        let dir = std::env::temp_dir().join(format!("aoc-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        Files {
+            submissions: dir.join("1.submissions"),
+            answers: dir.join("1.answers"),
+        }
+    }
+
+    #[test]
+    fn read_verdicts() {
+        let verdict = |text| Verdict::from_page(&page(text)).unwrap();
+        assert_eq!(
+            verdict("That's the right answer! You are one gold star closer."),
+            Verdict::Correct
+        );
+        assert_eq!(
+            verdict(
+                "That's not the right answer; your answer is too high. Please wait one minute."
+            ),
+            Verdict::TooHigh
+        );
+        assert_eq!(
+            verdict("That's not the right answer; your answer is too low."),
+            Verdict::TooLow
+        );
+        assert_eq!(
+            verdict("That's not the right answer. If you're stuck..."),
+            Verdict::Incorrect
+        );
+        assert_eq!(
+            verdict("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait."),
+            Verdict::RateLimited(Some(Duration::from_secs(65)))
+        );
+        for odd in [
+            "You have 5é left to wait",
+            "You have  left to wait",
+            "You have s left to wait",
+        ] {
+            assert_eq!(wait_time(odd), None, "{}", odd);
+        }
+        assert_eq!(wait_time("You have 99999999999999999h left to wait"), None);
+        assert_eq!(
+            verdict("You don't seem to be solving the right level.  Did you already complete it?"),
+            Verdict::AlreadySolved
+        );
+        assert!(Verdict::from_page("<html>502 Bad Gateway</html>").is_err());
+    }
+
+    #[test]
+    fn refuse_known_wrong_answers() {
+        let history = History {
+            submissions: vec![
+                Submission {
+                    part: Part::One,
+                    verdict: Verdict::TooHigh,
+                    answer: "5000".into(),
+                },
+                Submission {
+                    part: Part::One,
+                    verdict: Verdict::TooLow,
+                    answer: "100".into(),
+                },
+                Submission {
+                    part: Part::One,
+                    verdict: Verdict::Incorrect,
+                    answer: "ABC".into(),
+                },
+                Submission {
+                    part: Part::Two,
+                    verdict: Verdict::Correct,
+                    answer: "7".into(),
+                },
+            ],
+        };
+        assert!(history.refusal(Part::One, "5000").is_some());
+        assert!(history.refusal(Part::One, "6000").is_some());
+        assert!(history.refusal(Part::One, "100").is_some());
+        assert!(history.refusal(Part::One, "-4").is_some());
+        assert!(history.refusal(Part::One, "ABC").is_some());
+        assert!(history.refusal(Part::Two, "8").is_some());
+        assert_eq!(history.refusal(Part::One, "4999"), None);
+        assert_eq!(history.refusal(Part::One, "XYZ"), None);
+    }
+
+    #[test]
+    fn submit_and_record() {
+        let server = mock::serve(vec![
+            (
+                200,
+                page("That's not the right answer; your answer is too high."),
+            ),
+            (
+                200,
+                page("That's the right answer! You are one gold star closer."),
+            ),
+        ]);
+        let config = Config {
+            base_url: server.base_url.clone(),
+            session: Some("abc".into()),
+        };
+        let client = Client::new(&config).unwrap();
+        let files = temp_files("submit");
+        // An answer stored by verify --save does not stop a submission
+        std::fs::write(&files.answers, "1?: 4000\n").unwrap();
+
+        let verdict = submit_to(&client, 2022, 1, Part::One, "5000", &files).unwrap();
+        assert_eq!(verdict, Verdict::TooHigh);
+        let err = submit_to(&client, 2022, 1, Part::One, "5001", &files).unwrap_err();
+        assert!(err.to_string().contains("too high"));
+        let verdict = submit_to(&client, 2022, 1, Part::One, "4000", &files).unwrap();
+        assert_eq!(verdict, Verdict::Correct);
+        assert!(submit_to(&client, 2022, 1, Part::One, "4000", &files).is_err());
+
+        let requests = server.requests.lock().unwrap();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].line, "POST /2022/day/1/answer");
+        assert_eq!(requests[0].body, "level=1&answer=5000");
+        assert_eq!(
+            std::fs::read_to_string(&files.submissions).unwrap(),
+            "1 too-high 5000\n1 correct 4000\n"
+        );
+        assert_eq!(
+            std::fs::read_to_string(&files.answers).unwrap(),
+            "1: 4000\n"
+        );
+        std::fs::remove_dir_all(files.answers.parent().unwrap()).unwrap();
+    }
+}
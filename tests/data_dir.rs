@@ -0,0 +1,68 @@
+//! Runs the binary, as `AOC_DATA_DIR` and stdin are shared by the whole process.
+
+use std::{
+    io::Write,
+    process::{Command, Output, Stdio},
+};
+
+const EXAMPLE: &str = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n";
+
+fn aoc(args: &[&str], data_dir: &str, stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(args)
+        .env("AOC_DATA_DIR", data_dir)
+        // Without a session nothing can be downloaded, the input has to be found
+        .env("AOC_CONFIG", "/nonexistent/aoc-config")
+        .env_remove("AOC_SESSION")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn read_input_from_data_dir() {
+    let dir = std::env::temp_dir().join(format!("aoc-data-dir-{}", std::process::id()));
+    std::fs::create_dir_all(dir.join("2022")).unwrap();
+    std::fs::write(dir.join("2022/1.input"), EXAMPLE).unwrap();
+
+    let output = aoc(&["-y", "2022", "-d", "1"], dir.to_str().unwrap(), "");
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    std::fs::remove_dir_all(&dir).unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(
+        stdout,
+        "2022 day 1, part 1: 24000\n2022 day 1, part 2: 45000\n"
+    );
+}
+
+#[test]
+fn read_input_from_stdin() {
+    let crlf = EXAMPLE.replace('\n', "\r\n");
+    let output = aoc(
+        &["-y", "2022", "-d", "1", "-p", "1", "-i", "-"],
+        "/nonexistent",
+        &crlf,
+    );
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "2022 day 1, part 1: 24000\n"
+    );
+}